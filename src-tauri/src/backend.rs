@@ -157,10 +157,17 @@ impl BotConfig {
 }
 
 pub fn calculate_timeout_ms(lure_value: f32) -> u64 {
+    // NaN falls back to a neutral 1.0; infinities clamp like any other lure
+    let lure_value = if lure_value.is_nan() {
+        1.0
+    } else {
+        lure_value.max(0.0)
+    };
+    // Both branches meet at lure 1.0 (multiplier 1.0) so the timeout is continuous
     let multiplier = if lure_value <= 1.0 {
         3.0 - 2.0 * lure_value
     } else {
-        1.25 - lure_value / 3.0
+        (4.0 - lure_value) / 3.0
     };
 
     let seconds = (multiplier * 60.0 + 5.0).clamp(10.0, 180.0);
@@ -421,7 +428,22 @@ fn log_event(state: &SharedState, level: &str, message: &str) {
 fn worker_loop(state: SharedState, window: Window) {
     let start_time = Instant::now();
     let mut last_uptime_minutes = 0;
-    let mut enigo = Enigo::new(&Settings::default()).expect("Failed to init Enigo");
+    let mut enigo = match Enigo::new(&Settings::default()) {
+        Ok(enigo) => enigo,
+        Err(err) => {
+            state.running.store(false, Ordering::Relaxed);
+            state.session.write().running = false;
+            update_error_state(
+                &state,
+                &window,
+                &format!(
+                    "Could not initialize input - check accessibility permissions ({})",
+                    err
+                ),
+            );
+            return;
+        }
+    };
 
     thread::sleep(Duration::from_millis(state.config.read().startup_delay_ms));
     log_event(&state, "INFO", "Worker loop started");
@@ -657,7 +679,8 @@ pub fn start_bot(state: &SharedState, window: &Window) {
     log_event(state, "INFO", "Session started");
 
     let mut handle_guard = state.worker_handle.lock().expect("worker handle lock");
-    if handle_guard.is_none() {
+    // A worker that bailed out on its own (e.g. input init failed) can be replaced
+    if !matches!(handle_guard.as_ref(), Some(handle) if !handle.is_finished()) {
         let thread_state = state.clone();
         let thread_window = window.clone();
         *handle_guard = Some(thread::spawn(move || worker_loop(thread_state, thread_window)));
@@ -683,3 +706,34 @@ pub fn stop_bot(state: &SharedState, window: &Window) {
         let _ = handle.join();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_is_continuous_across_lure_one() {
+        assert_eq!(calculate_timeout_ms(1.0), 65_000);
+
+        let below = calculate_timeout_ms(0.999) as i64;
+        let above = calculate_timeout_ms(1.001) as i64;
+        assert!((below - 65_000).abs() <= 200, "below 1.0: {}", below);
+        assert!((above - 65_000).abs() <= 200, "above 1.0: {}", above);
+        assert!(below > 65_000 && above < 65_000);
+    }
+
+    #[test]
+    fn timeout_is_clamped_at_both_ends() {
+        assert_eq!(calculate_timeout_ms(0.0), 180_000);
+        assert_eq!(calculate_timeout_ms(-5.0), 180_000);
+        assert_eq!(calculate_timeout_ms(4.0), 10_000);
+        assert_eq!(calculate_timeout_ms(100.0), 10_000);
+        assert_eq!(calculate_timeout_ms(f32::INFINITY), 10_000);
+        assert_eq!(calculate_timeout_ms(f32::NEG_INFINITY), 180_000);
+    }
+
+    #[test]
+    fn nan_lure_uses_the_neutral_timeout() {
+        assert_eq!(calculate_timeout_ms(f32::NAN), 65_000);
+    }
+}
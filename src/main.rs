@@ -12,82 +12,709 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+// ===== TEXT MODULE =====
+mod text {
+    fn is_emoji(c: char) -> bool {
+        matches!(
+            c as u32,
+            0x200D                // zero-width joiner
+            | 0x2300..=0x23FF     // misc technical (⏱ ⏸ ⏹)
+            | 0x25A0..=0x25FF     // geometric shapes (▶)
+            | 0x2600..=0x27BF     // misc symbols and dingbats
+            | 0x2B00..=0x2BFF     // misc symbols and arrows
+            | 0xFE0F              // emoji variation selector
+            | 0x1F000..=0x1FAFF // emoji blocks
+        )
+    }
+
+    /// Removes emoji from `text` and trims the whitespace they leave at line starts.
+    pub fn plain_text(text: &str) -> String {
+        text.lines()
+            .map(|line| {
+                let stripped: String = line.chars().filter(|&c| !is_emoji(c)).collect();
+                stripped.trim().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// ===== I18N MODULE =====
+mod i18n {
+    use super::*;
+    use once_cell::sync::Lazy;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+    pub enum Lang {
+        #[default]
+        English,
+        Spanish,
+    }
+
+    impl Lang {
+        pub const ALL: [Lang; 2] = [Lang::English, Lang::Spanish];
+
+        pub fn display_name(&self) -> &'static str {
+            match self {
+                Lang::English => "English",
+                Lang::Spanish => "Español",
+            }
+        }
+
+        fn from_index(index: u8) -> Self {
+            match index {
+                1 => Lang::Spanish,
+                _ => Lang::English,
+            }
+        }
+    }
+
+    // Owned values, since borrowed `&str`s can't hold strings with JSON escapes
+    type Table = HashMap<String, String>;
+
+    fn parse_table(json: &str) -> Table {
+        serde_json::from_str(json).expect("embedded translation table is invalid JSON")
+    }
+
+    static ENGLISH: Lazy<Table> = Lazy::new(|| parse_table(include_str!("i18n/en.json")));
+    static SPANISH: Lazy<Table> = Lazy::new(|| parse_table(include_str!("i18n/es.json")));
+    static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+    pub fn set_language(lang: Lang) {
+        CURRENT.store(lang as u8, Ordering::Relaxed);
+    }
+
+    fn table(lang: Lang) -> &'static Table {
+        match lang {
+            Lang::English => &ENGLISH,
+            Lang::Spanish => &SPANISH,
+        }
+    }
+
+    /// Looks up `key` in the current language, falling back to English and then the key itself.
+    pub fn t(key: &'static str) -> &'static str {
+        let lang = Lang::from_index(CURRENT.load(Ordering::Relaxed));
+        table(lang)
+            .get(key)
+            .or_else(|| ENGLISH.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    /// Like [`t`], replacing each `{name}` placeholder with its value from `args`.
+    pub fn tf(key: &'static str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        args.iter().fold(t(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn tables_keep_escaped_values() {
+            let table = parse_table(r#"{"a": "line\nbreak", "b": "say \"hi\""}"#);
+            assert_eq!(table["a"], "line\nbreak");
+            assert_eq!(table["b"], "say \"hi\"");
+        }
+
+        #[test]
+        fn languages_share_the_same_keys() {
+            let mut english: Vec<_> = ENGLISH.keys().collect();
+            let mut spanish: Vec<_> = SPANISH.keys().collect();
+            english.sort();
+            spanish.sort();
+            assert_eq!(english, spanish);
+        }
+    }
+}
+
 // ===== CONFIG MODULE =====
 mod config {
     use super::*;
+    use i18n::Lang;
     use std::fs;
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(default)]
     pub struct BotConfig {
         pub color_tolerance: u8,
+        /// Extra tolerance added in full darkness, scaled down as the region brightens; 0 disables.
+        pub low_light_tolerance_boost: u8,
+        /// Mean region brightness (0-255) below which the boost starts to apply.
+        pub low_light_brightness: u8,
+        pub min_match_pixels: u32,
+        pub min_cluster_pixels: u32,
+        /// Use `min_match_fraction` of each region's area in place of the absolute
+        /// match and cluster pixel counts, so tuning carries across resolutions.
+        pub match_fraction_enabled: bool,
+        /// Share of the region's pixels (0.001 = 0.1%) that must match.
+        pub min_match_fraction: f32,
+        /// Check every Nth pixel in each axis during color matching; 1 checks them all.
+        pub detection_downsample: u32,
+        /// Hold the loop while the whole display is near-black (asleep, or the game
+        /// minimized). Off by default, since dark scenes can trip it.
+        pub blank_frame_pause_enabled: bool,
+        pub blank_frame_ratio: f32,
+        /// Wait while `loading_region` is mostly `loading_color`, as on teleport screens.
+        pub loading_check_enabled: bool,
+        pub loading_region: Region,
+        pub loading_color: [u8; 3],
+        /// Percent of `loading_region` that must match `loading_color`.
+        pub loading_ratio: f32,
+        pub spots: Vec<Spot>,
+        pub spot_rotation_enabled: bool,
+        pub spot_rotation_minutes: u32,
+        pub spot_rotation_fish: u32,
+        pub session_fish_goal: u32,
+        pub stop_at_session_goal: bool,
+        /// Replays the same random timings every session when non-zero, for tests
+        /// and bug reports. Only settable in config.json.
+        pub rng_seed: u64,
+        pub cast_click_pos: Option<(i32, i32)>,
+        pub reel_click_pos: Option<(i32, i32)>,
+        /// Click inside the catch region at `reel_click_offset` instead of `reel_click_pos`.
+        pub reel_click_in_region: bool,
+        /// Point in the catch region as fractions of its width and height; 0.5, 0.5 is the center.
+        pub reel_click_offset: [f32; 2],
         pub autoclick_interval_ms: u64,
+        /// Reel click intervals repeated in order; empty clicks every `autoclick_interval_ms`.
+        pub reel_click_pattern_ms: Vec<u64>,
+        pub reel_scan_interval_ms: u64,
+        pub post_catch_delay_ms: u64,
+        /// Wait after casting before bite detection starts, while the line settles.
+        pub cast_settle_ms: u64,
         pub fish_per_feed: u32,
+        /// Minimum gap between blind feeds while hunger can't be read; 0 feeds on every failed read.
+        /// Without Tesseract it is also the feeding interval, at least a minute.
+        pub safety_feed_cooldown_secs: u32,
+        /// Catches either side of `fish_per_feed` each feed may land on; 0 feeds on the exact count.
+        pub feed_jitter: u32,
         pub webhook_url: String,
+        pub webhook_persist_queue: bool,
+        pub webhook_batch_size: u32,
+        pub webhook_message_delay_ms: u64,
+        pub webhook_idle_poll_ms: u64,
         pub screenshot_interval_mins: u32,
         pub screenshot_enabled: bool,
         pub red_region: Region,
         pub yellow_region: Region,
         pub hunger_region: Region,
         pub region_preset: String,
+        /// Presets saved from the current regions, alongside the built-in ones.
+        pub custom_presets: Vec<RegionPreset>,
         pub startup_delay_ms: u64,
+        /// Extra wait before the first cast of a session only, for slow-loading worlds.
+        pub first_cast_delay_ms: u64,
+        pub auto_start_on_launch: bool,
+        pub auto_start_delay_secs: u32,
+        /// Read only by the Tauri backend, which shares this config file.
         pub detection_interval_ms: u64,
+        pub screenshot_cache_ms: u64,
+        pub bite_scan_interval_ms: u64,
         pub max_fishing_timeout_ms: u64,
         pub rod_lure_value: f32,
         pub always_on_top: bool,
+        /// Shrink the window to a strip with phase, fish count and start/stop.
+        pub compact_mode: bool,
+        /// Top of the fish/hr gauges, or their floor when auto-scaling.
+        pub gauge_max_fish_per_hour: f32,
+        /// Grow the fish/hr gauges to fit the session's fastest observed rate.
+        pub gauge_auto_scale: bool,
         pub auto_save_enabled: bool,
+        /// Check capture, regions, Tesseract and input at launch and log the results.
+        pub self_check_on_startup: bool,
         pub failsafe_enabled: bool,
+        /// Log clicks and key presses instead of sending them while detection
+        /// runs normally. Read when the input backend is built.
+        pub input_dry_run: bool,
+        pub windows_input_method: WindowsInputMethod,
+        /// Shows developer tools such as the simulated catch. Only settable in
+        /// config.json; the tools also require `input_dry_run`.
+        pub dev_mode: bool,
+        /// Index into the connected displays that regions are captured from.
+        pub screen_index: usize,
+        /// Treat capture regions as offsets from the Roblox window's client area (Windows only).
+        pub window_relative_regions: bool,
         pub advanced_detection: bool,
+        pub daily_summary_enabled: bool,
+        pub daily_summary_hour: u32,
+        pub daily_summary_minute: u32,
+        pub bite_detection_mode: BiteDetectionMode,
+        pub motion_delta_threshold: f32,
+        pub screenshot_format: ScreenshotFormat,
+        pub jpeg_quality: u8,
+        pub screenshot_max_dimension: u32,
+        pub status_server_enabled: bool,
+        pub status_server_port: u16,
+        pub status_server_token: String,
+        /// Port of the `/ws` WebSocket feed, served whenever the status server is.
+        pub status_ws_port: u16,
+        /// Pause when fewer than `low_success_rate_floor` percent of the last
+        /// `low_success_window` casts caught a fish. Off by default.
+        pub low_success_pause_enabled: bool,
+        pub low_success_rate_floor: f32,
+        pub low_success_window: u32,
+        /// Reel timeouts in a row before the bot pauses; 0 never pauses.
+        pub max_reel_timeouts: u32,
+        pub adaptive_timing: bool,
+        pub target_scan_rate_hz: u32,
+        pub anti_afk_enabled: bool,
+        pub anti_afk_idle_secs: u64,
+        pub rod_check_enabled: bool,
+        pub rod_region: Region,
+        pub rod_equipped_color: [u8; 3],
+        pub popup_check_enabled: bool,
+        pub popup_region: Region,
+        pub popup_color: [u8; 3],
+        pub popup_dismiss: PopupDismiss,
+        /// Wait after a bite for the minigame's start indicator before reeling.
+        pub minigame_start_check_enabled: bool,
+        pub minigame_start_region: Region,
+        pub minigame_start_color: [u8; 3],
+        /// How long to wait for the indicator before reeling anyway.
+        pub minigame_start_timeout_ms: u64,
+        /// Steps run after every catch and at startup to re-ready the rod.
+        pub rod_reset_sequence: Vec<InputStep>,
+        /// Steps run to eat when hunger is low.
+        pub feed_sequence: Vec<InputStep>,
+        pub gold_tracking_enabled: bool,
+        pub gold_region: Region,
+        pub gold_check_interval_secs: u64,
+        pub ocr_psm: i32,
+        pub ocr_dpi: i32,
+        pub ocr_whitelist: String,
+        pub ocr_contrast_stretch: bool,
+        pub ocr_gamma: f32,
+        pub ocr_invert: bool,
+        /// Tesseract binary or its folder; empty searches PATH and the usual install folders.
+        pub tesseract_path: String,
+        pub milestone_interval: u32,
+        pub milestone_message_template: String,
+        pub notify_on_start: bool,
+        pub notify_on_stop: bool,
+        pub notify_on_pause: bool,
+        pub notify_on_milestone: bool,
+        pub notify_on_error: bool,
+        /// Minimum gap between error alerts; ones in between are counted and noted.
+        pub error_alert_cooldown_secs: u64,
+        /// Minimum gap between milestone messages; 0 sends every milestone.
+        pub milestone_cooldown_secs: u64,
+        pub error_screenshot_enabled: bool,
+        /// Attach a screenshot to the start notification.
+        pub send_startup_screenshot: bool,
+        /// Append every catch time to `catches.csv` in the data folder.
+        pub catch_log_csv: bool,
+        /// Append matched-pixel counts at each bite and catch to `match_counts.csv`.
+        pub match_log_csv: bool,
+        pub sound_on_catch: bool,
+        /// Play the alert tone when the bot stops for errors or the failsafe.
+        pub sound_on_error_stop: bool,
+        /// 0.0-1.0
+        pub sound_volume: f32,
+        pub plain_text_mode: bool,
+        /// Most activity log lines kept in the UI.
+        pub status_log_max_messages: usize,
+        /// Drop activity log lines older than this; 0 keeps them regardless of age.
+        pub status_log_retention_minutes: u32,
+        pub language: Lang,
+        pub theme: Theme,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum Theme {
+        ArcaneDark,
+        Light,
+        HighContrast,
+    }
+
+    impl Theme {
+        pub const ALL: [Theme; 3] = [Theme::ArcaneDark, Theme::Light, Theme::HighContrast];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                Theme::ArcaneDark => "Arcane Dark",
+                Theme::Light => "Light",
+                Theme::HighContrast => "High Contrast",
+            }
+        }
+    }
+
+    /// How clicks and key presses reach the game on Windows.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum WindowsInputMethod {
+        /// Global input through `SendInput`, as if from real hardware.
+        SendInput,
+        /// Mouse and key messages posted straight to the Roblox window.
+        PostMessage,
+    }
+
+    impl WindowsInputMethod {
+        pub const ALL: [WindowsInputMethod; 2] = [
+            WindowsInputMethod::SendInput,
+            WindowsInputMethod::PostMessage,
+        ];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                WindowsInputMethod::SendInput => "SendInput",
+                WindowsInputMethod::PostMessage => "PostMessage",
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum ScreenshotFormat {
+        Jpeg,
+        Png,
+    }
+
+    impl ScreenshotFormat {
+        pub fn file_name(&self) -> &'static str {
+            match self {
+                ScreenshotFormat::Jpeg => "screenshot.jpg",
+                ScreenshotFormat::Png => "screenshot.png",
+            }
+        }
+
+        pub fn mime_type(&self) -> &'static str {
+            match self {
+                ScreenshotFormat::Jpeg => "image/jpeg",
+                ScreenshotFormat::Png => "image/png",
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum BiteDetectionMode {
+        /// Match the exclamation mark against a fixed RGB target.
+        Color,
+        /// Trigger on a sudden change between consecutive frames of the red region.
+        MotionDelta,
+    }
+
+    /// Regions saved under a name from the resolution presets panel.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RegionPreset {
+        pub name: String,
+        pub red_region: Region,
+        pub yellow_region: Region,
+        pub hunger_region: Region,
+    }
+
+    /// One fishing location in the rotation.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Spot {
+        pub name: String,
+        /// Where to click to cast; `None` clicks wherever the cursor is.
+        pub cast_position: Option<(i32, i32)>,
+        pub red_region: Region,
+        pub yellow_region: Region,
+        /// Keys held in order to walk here from the previous spot.
+        pub movement: Vec<MoveStep>,
+    }
+
+    /// One action in a configurable input sequence.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum InputStep {
+        Click,
+        /// A short key press.
+        Tap(char),
+        /// Keep a key down for `ms`, for mechanics that need a held key.
+        Hold {
+            key: char,
+            ms: u64,
+        },
+        /// Pause before the next step.
+        Wait(u64),
+    }
+
+    impl InputStep {
+        /// Switches the hotbar away from the rod and back.
+        pub fn default_rod_reset() -> Vec<InputStep> {
+            vec![
+                InputStep::Tap('5'),
+                InputStep::Wait(200),
+                InputStep::Tap('5'),
+                InputStep::Wait(200),
+            ]
+        }
+
+        /// Equips food from slot 6, eats it and re-equips the rod.
+        pub fn default_feed() -> Vec<InputStep> {
+            vec![
+                InputStep::Click,
+                InputStep::Wait(200),
+                InputStep::Tap('6'),
+                InputStep::Wait(200),
+                InputStep::Click,
+                InputStep::Wait(200),
+                InputStep::Tap('5'),
+                InputStep::Wait(200),
+            ]
+        }
     }
 
     #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+    pub struct MoveStep {
+        pub key: char,
+        pub hold_ms: u64,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum PopupDismiss {
+        /// Click at a screen position, e.g. the popup's OK or close button.
+        Click { x: i32, y: i32 },
+        /// Press a key the game binds to closing the popup.
+        Key(char),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
     pub struct Region {
         pub x: i32,
         pub y: i32,
         pub width: u32,
         pub height: u32,
+        /// Pixels trimmed from every edge before capturing.
+        #[serde(default)]
+        pub inset: u32,
+    }
+
+    impl Region {
+        /// A region captured as-is, with no inset.
+        pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+            Self {
+                x,
+                y,
+                width,
+                height,
+                inset: 0,
+            }
+        }
+
+        /// The area actually captured: shrunk by `inset` on each side, never below 1x1.
+        pub fn inset_applied(&self) -> Region {
+            let inset = self
+                .inset
+                .min(self.width.saturating_sub(1) / 2)
+                .min(self.height.saturating_sub(1) / 2);
+            Region::new(
+                self.x + inset as i32,
+                self.y + inset as i32,
+                self.width - inset * 2,
+                self.height - inset * 2,
+            )
+        }
+
+        /// Point at fractions `fx`, `fy` (0..1) across the captured area.
+        pub fn point_at(&self, fx: f32, fy: f32) -> (i32, i32) {
+            let area = self.inset_applied();
+            (
+                area.x + (area.width as f32 * fx.clamp(0.0, 1.0)).round() as i32,
+                area.y + (area.height as f32 * fy.clamp(0.0, 1.0)).round() as i32,
+            )
+        }
+
+        /// Parses `x,y,w,h` (commas or whitespace between the numbers) as shared
+        /// by other tools. The inset is left at 0.
+        pub fn parse_xywh(text: &str) -> Result<Region> {
+            let parts: Vec<&str> = text
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .collect();
+            if parts.len() != 4 {
+                return Err(anyhow!("expected x,y,w,h, got {} values", parts.len()));
+            }
+
+            let x: i32 = parts[0]
+                .parse()
+                .map_err(|_| anyhow!("bad x: {}", parts[0]))?;
+            let y: i32 = parts[1]
+                .parse()
+                .map_err(|_| anyhow!("bad y: {}", parts[1]))?;
+            let width: u32 = parts[2]
+                .parse()
+                .map_err(|_| anyhow!("bad width: {}", parts[2]))?;
+            let height: u32 = parts[3]
+                .parse()
+                .map_err(|_| anyhow!("bad height: {}", parts[3]))?;
+            if !(1..=8000).contains(&width) || !(1..=8000).contains(&height) {
+                return Err(anyhow!("width and height must be 1-8000"));
+            }
+
+            Ok(Region::new(x, y, width, height))
+        }
+
+        pub fn to_xywh(self) -> String {
+            format!("{},{},{},{}", self.x, self.y, self.width, self.height)
+        }
+
+        /// Moves and resizes this region to pasted `x,y,w,h` text, keeping its
+        /// inset. Leaves the region untouched when the text doesn't parse.
+        pub fn apply_xywh(&mut self, text: &str) -> Result<()> {
+            *self = Region {
+                inset: self.inset,
+                ..Self::parse_xywh(text)?
+            };
+            Ok(())
+        }
     }
 
+    /// Earlier configs kept beside config.json as `.1` (newest) to `.N`.
+    pub const CONFIG_SNAPSHOTS: usize = 3;
+
     impl Default for BotConfig {
         fn default() -> Self {
             Self {
                 color_tolerance: 10,
+                low_light_tolerance_boost: 0,
+                low_light_brightness: 80,
+                min_match_pixels: 10,
+                detection_downsample: 1,
+                min_cluster_pixels: 8,
+                match_fraction_enabled: false,
+                min_match_fraction: 0.001,
+                blank_frame_pause_enabled: false,
+                blank_frame_ratio: 99.0,
+                loading_check_enabled: false,
+                loading_region: Region::new(1220, 420, 1000, 600),
+                loading_color: [45, 45, 45],
+                loading_ratio: 90.0,
+                spots: Vec::new(),
+                spot_rotation_enabled: false,
+                spot_rotation_minutes: 30,
+                spot_rotation_fish: 0,
+                session_fish_goal: 0,
+                stop_at_session_goal: false,
+                rng_seed: 0,
+                cast_click_pos: None,
+                reel_click_pos: None,
+                reel_click_in_region: false,
+                reel_click_offset: [0.5, 0.5],
                 autoclick_interval_ms: 70,
+                reel_click_pattern_ms: Vec::new(),
+                reel_scan_interval_ms: 70,
+                post_catch_delay_ms: 0,
+                cast_settle_ms: 100,
                 fish_per_feed: 5,
+                feed_jitter: 0,
+                safety_feed_cooldown_secs: 300,
                 webhook_url: String::new(),
+                webhook_persist_queue: false,
+                webhook_batch_size: 5,
+                webhook_message_delay_ms: 500,
+                webhook_idle_poll_ms: 2000,
                 screenshot_interval_mins: 60,
                 screenshot_enabled: true,
-                red_region: Region {
-                    x: 1321,
-                    y: 99,
-                    width: 768,
-                    height: 546,
-                },
-                yellow_region: Region {
-                    x: 3097,
-                    y: 1234,
-                    width: 342,
-                    height: 205,
-                },
-                hunger_region: Region {
-                    x: 274,
-                    y: 1301,
-                    width: 43,
-                    height: 36,
-                },
+                red_region: Region::new(1321, 99, 768, 546),
+                yellow_region: Region::new(3097, 1234, 342, 205),
+                hunger_region: Region::new(274, 1301, 43, 36),
                 region_preset: "3440x1440".to_string(),
+                custom_presets: Vec::new(),
                 startup_delay_ms: 3000,
+                first_cast_delay_ms: 0,
+                auto_start_on_launch: false,
+                auto_start_delay_secs: 60,
                 detection_interval_ms: 50,
+                screenshot_cache_ms: 50,
+                bite_scan_interval_ms: 50,
                 max_fishing_timeout_ms: 25000,
                 rod_lure_value: 1.0,
                 always_on_top: false,
+                compact_mode: false,
+                gauge_max_fish_per_hour: 120.0,
+                gauge_auto_scale: false,
                 auto_save_enabled: true,
+                self_check_on_startup: true,
                 failsafe_enabled: true,
+                input_dry_run: false,
+                windows_input_method: WindowsInputMethod::SendInput,
+                dev_mode: false,
+                screen_index: 0,
+                window_relative_regions: false,
                 advanced_detection: false,
+                daily_summary_enabled: false,
+                daily_summary_hour: 20,
+                daily_summary_minute: 0,
+                bite_detection_mode: BiteDetectionMode::Color,
+                motion_delta_threshold: 12.0,
+                screenshot_format: ScreenshotFormat::Jpeg,
+                jpeg_quality: 80,
+                screenshot_max_dimension: 1600,
+                status_server_enabled: false,
+                status_server_port: 8787,
+                status_server_token: String::new(),
+                status_ws_port: 8788,
+                low_success_pause_enabled: false,
+                low_success_rate_floor: 40.0,
+                low_success_window: 50,
+                max_reel_timeouts: 5,
+                adaptive_timing: false,
+                target_scan_rate_hz: 20,
+                anti_afk_enabled: false,
+                anti_afk_idle_secs: 600,
+                rod_check_enabled: false,
+                rod_region: Region::new(1700, 1380, 40, 40),
+                rod_equipped_color: [255, 255, 255],
+                popup_check_enabled: false,
+                popup_region: Region::new(1520, 620, 400, 200),
+                popup_color: [255, 255, 255],
+                popup_dismiss: PopupDismiss::Click { x: 1720, y: 800 },
+                minigame_start_check_enabled: false,
+                minigame_start_region: Region::new(3097, 1234, 342, 205),
+                minigame_start_color: [255, 255, 255],
+                minigame_start_timeout_ms: 1500,
+                rod_reset_sequence: InputStep::default_rod_reset(),
+                feed_sequence: InputStep::default_feed(),
+                gold_tracking_enabled: false,
+                gold_region: Region::new(60, 1360, 220, 36),
+                gold_check_interval_secs: 60,
+                ocr_psm: 8,
+                ocr_dpi: 150,
+                ocr_whitelist: "0123456789%/".to_string(),
+                ocr_contrast_stretch: false,
+                ocr_gamma: 1.0,
+                ocr_invert: false,
+                tesseract_path: String::new(),
+                milestone_interval: 10,
+                milestone_message_template:
+                    "🎉 Milestone Reached! {count} fish caught this session!".to_string(),
+                notify_on_start: true,
+                notify_on_stop: true,
+                notify_on_pause: true,
+                notify_on_milestone: true,
+                notify_on_error: true,
+                error_alert_cooldown_secs: 60,
+                milestone_cooldown_secs: 60,
+                catch_log_csv: false,
+                match_log_csv: false,
+                sound_on_catch: false,
+                sound_on_error_stop: false,
+                sound_volume: 0.5,
+                status_log_max_messages: 100,
+                status_log_retention_minutes: 0,
+                error_screenshot_enabled: true,
+                send_startup_screenshot: true,
+                plain_text_mode: false,
+                language: Lang::English,
+                theme: Theme::ArcaneDark,
             }
         }
     }
 
     impl BotConfig {
+        pub fn reset_ocr_settings(&mut self) {
+            let defaults = Self::default();
+            self.ocr_psm = defaults.ocr_psm;
+            self.ocr_dpi = defaults.ocr_dpi;
+            self.ocr_whitelist = defaults.ocr_whitelist;
+            self.ocr_contrast_stretch = defaults.ocr_contrast_stretch;
+            self.ocr_gamma = defaults.ocr_gamma;
+            self.ocr_invert = defaults.ocr_invert;
+        }
+
         pub fn load() -> Result<Self> {
             let path = Self::config_path();
             if path.exists() {
@@ -106,10 +733,52 @@ mod config {
                 fs::create_dir_all(parent)?;
             }
             let json = serde_json::to_string_pretty(self)?;
+            Self::rotate_snapshots(&path, &json);
             fs::write(path, json)?;
             Ok(())
         }
 
+        /// Shifts `config.json.1..` down one and copies the current file to `.1`,
+        /// if it still loads and differs from what is about to be written.
+        fn rotate_snapshots(path: &std::path::Path, new_json: &str) {
+            let Ok(current) = fs::read_to_string(path) else {
+                return;
+            };
+            if current == new_json || serde_json::from_str::<Self>(&current).is_err() {
+                return;
+            }
+
+            for n in (1..CONFIG_SNAPSHOTS).rev() {
+                let from = Self::snapshot_path(n);
+                if from.exists() {
+                    fs::rename(&from, Self::snapshot_path(n + 1)).ok();
+                }
+            }
+            if let Err(e) = fs::write(Self::snapshot_path(1), current) {
+                log::warn!("Failed to snapshot config: {}", e);
+            }
+        }
+
+        /// Saved snapshots that still load, newest first, with when each was taken.
+        pub fn snapshots() -> Vec<(PathBuf, chrono::DateTime<Local>)> {
+            (1..=CONFIG_SNAPSHOTS)
+                .map(Self::snapshot_path)
+                .filter(|path| Self::load_snapshot(path).is_ok())
+                .filter_map(|path| {
+                    let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+                    Some((path, modified.into()))
+                })
+                .collect()
+        }
+
+        pub fn load_snapshot(path: &std::path::Path) -> Result<Self> {
+            Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+        }
+
+        fn snapshot_path(n: usize) -> PathBuf {
+            Self::config_path().with_file_name(format!("config.json.{}", n))
+        }
+
         fn config_path() -> PathBuf {
             directories::ProjectDirs::from("com", "arcane", "fishing-bot")
                 .map(|dirs| dirs.config_dir().join("config.json"))
@@ -117,11 +786,17 @@ mod config {
         }
 
         pub fn calculate_max_bite_time(&self) -> Duration {
-            let lure = self.rod_lure_value;
+            // NaN falls back to a neutral 1.0; infinities clamp like any other lure
+            let lure = if self.rod_lure_value.is_nan() {
+                1.0
+            } else {
+                self.rod_lure_value.max(0.0)
+            };
+            // Both branches meet at lure 1.0 (multiplier 1.0) so the timeout is continuous
             let multiplier = if lure <= 1.0 {
                 3.0 - 2.0 * lure
             } else {
-                1.25 - lure / 3.0
+                (4.0 - lure) / 3.0
             };
 
             let seconds = (multiplier * 60.0 + 5.0).clamp(10.0, 180.0);
@@ -140,52 +815,49 @@ mod config {
         pub fn apply_resolution_preset(&mut self, preset: &str) {
             match preset {
                 "3440x1440" => {
-                    self.red_region = Region {
-                        x: 1321,
-                        y: 99,
-                        width: 768,
-                        height: 546,
-                    };
-                    self.yellow_region = Region {
-                        x: 3097,
-                        y: 1234,
-                        width: 342,
-                        height: 205,
-                    };
-                    self.hunger_region = Region {
-                        x: 274,
-                        y: 1301,
-                        width: 43,
-                        height: 36,
-                    };
+                    self.red_region = Region::new(1321, 99, 768, 546);
+                    self.yellow_region = Region::new(3097, 1234, 342, 205);
+                    self.hunger_region = Region::new(274, 1301, 43, 36);
                 }
                 "1920x1080" => {
-                    self.red_region = Region {
-                        x: 598,
-                        y: 29,
-                        width: 901,
-                        height: 477,
-                    };
-                    self.yellow_region = Region {
-                        x: 1649,
-                        y: 632,
-                        width: 270,
-                        height: 447,
-                    };
-                    self.hunger_region = Region {
-                        x: 212,
-                        y: 984,
-                        width: 21,
-                        height: 18,
-                    };
+                    self.red_region = Region::new(598, 29, 901, 477);
+                    self.yellow_region = Region::new(1649, 632, 270, 447);
+                    self.hunger_region = Region::new(212, 984, 21, 18);
+                }
+                _ => {
+                    if let Some(custom) = self.custom_presets.iter().find(|p| p.name == preset) {
+                        self.red_region = custom.red_region;
+                        self.yellow_region = custom.yellow_region;
+                        self.hunger_region = custom.hunger_region;
+                    }
                 }
-                _ => {}
             }
             self.region_preset = preset.to_string();
         }
+
+        /// Stores the current regions as a custom preset, replacing one with the
+        /// same name, and selects it.
+        pub fn save_region_preset(&mut self, name: &str) {
+            let preset = RegionPreset {
+                name: name.to_string(),
+                red_region: self.red_region,
+                yellow_region: self.yellow_region,
+                hunger_region: self.hunger_region,
+            };
+            match self.custom_presets.iter_mut().find(|p| p.name == name) {
+                Some(existing) => *existing = preset,
+                None => self.custom_presets.push(preset),
+            }
+            self.region_preset = name.to_string();
+        }
+
+        pub fn delete_region_preset(&mut self, name: &str) {
+            self.custom_presets.retain(|p| p.name != name);
+        }
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(default)]
     pub struct LifetimeStats {
         pub total_fish_caught: u64,
         pub total_runtime_seconds: u64,
@@ -195,6 +867,157 @@ mod config {
         pub average_fish_per_hour: f32,
         pub total_feeds: u64,
         pub uptime_percentage: f32,
+        pub total_errors: u64,
+        pub daily_report_baseline: Option<ReportBaseline>,
+        pub session_history: Vec<SessionRecord>,
+        pub days_active: u64,
+        pub current_day_streak: u32,
+        pub best_day_streak: u32,
+        /// Local date (YYYY-MM-DD) of the last completed session.
+        pub last_active_day: Option<String>,
+    }
+
+    /// Summary of a single completed session.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct SessionRecord {
+        pub started_at: String,
+        pub fish: u64,
+        pub feeds: u64,
+        pub runtime_seconds: u64,
+        pub best_streak: u32,
+        pub errors: u32,
+    }
+
+    const MAX_SESSION_HISTORY: usize = 1000;
+
+    /// Progress of the running session, saved periodically so a crash doesn't lose it.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct SessionCheckpoint {
+        pub saved_at: String,
+        pub fish: u64,
+        pub feeds: u64,
+        pub runtime_seconds: u64,
+        pub best_streak: u32,
+        pub current_streak: u32,
+        pub errors: u32,
+    }
+
+    impl SessionCheckpoint {
+        pub fn save(&self) -> Result<()> {
+            let path = Self::checkpoint_path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let json = serde_json::to_string_pretty(self)?;
+            fs::write(path, json)?;
+            Ok(())
+        }
+
+        /// The checkpoint left by a session that never stopped cleanly, if it was
+        /// saved within `max_age`.
+        pub fn load_recent(max_age: chrono::Duration) -> Option<Self> {
+            let contents = fs::read_to_string(Self::checkpoint_path()).ok()?;
+            let checkpoint: Self = serde_json::from_str(&contents).ok()?;
+            let saved_at = chrono::DateTime::parse_from_rfc3339(&checkpoint.saved_at).ok()?;
+            (Local::now().signed_duration_since(saved_at) <= max_age).then_some(checkpoint)
+        }
+
+        pub fn clear() {
+            let path = Self::checkpoint_path();
+            if path.exists() {
+                fs::remove_file(path).ok();
+            }
+        }
+
+        fn checkpoint_path() -> PathBuf {
+            directories::ProjectDirs::from("com", "arcane", "fishing-bot")
+                .map(|dirs| dirs.data_dir().join("session.json"))
+                .unwrap_or_else(|| PathBuf::from("session.json"))
+        }
+    }
+
+    /// Append-only CSV of catch times, for charting catch cadence elsewhere.
+    pub struct CatchLog;
+
+    impl CatchLog {
+        pub fn append(
+            at: chrono::DateTime<Local>,
+            session_fish: u64,
+            since_previous: Option<Duration>,
+        ) -> Result<()> {
+            let path = Self::path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let is_new = !path.exists();
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)?;
+
+            let mut writer = csv::Writer::from_writer(file);
+            if is_new {
+                writer.write_record(["timestamp", "session_fish", "seconds_since_previous"])?;
+            }
+            writer.write_record([
+                at.to_rfc3339(),
+                session_fish.to_string(),
+                since_previous
+                    .map(|gap| format!("{:.1}", gap.as_secs_f32()))
+                    .unwrap_or_default(),
+            ])?;
+            writer.flush()?;
+            Ok(())
+        }
+
+        pub fn path() -> PathBuf {
+            directories::ProjectDirs::from("com", "arcane", "fishing-bot")
+                .map(|dirs| dirs.data_dir().join("catches.csv"))
+                .unwrap_or_else(|| PathBuf::from("catches.csv"))
+        }
+    }
+
+    /// Append-only CSV of matched-pixel counts at each bite and catch, for spotting
+    /// detection drift across days.
+    pub struct MatchLog;
+
+    impl MatchLog {
+        pub fn append(at: chrono::DateTime<Local>, kind: &str, pixels: u32) -> Result<()> {
+            let path = Self::path();
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let is_new = !path.exists();
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)?;
+
+            let mut writer = csv::Writer::from_writer(file);
+            if is_new {
+                writer.write_record(["timestamp", "kind", "matched_pixels"])?;
+            }
+            writer.write_record([at.to_rfc3339(), kind.to_string(), pixels.to_string()])?;
+            writer.flush()?;
+            Ok(())
+        }
+
+        pub fn path() -> PathBuf {
+            directories::ProjectDirs::from("com", "arcane", "fishing-bot")
+                .map(|dirs| dirs.data_dir().join("match_counts.csv"))
+                .unwrap_or_else(|| PathBuf::from("match_counts.csv"))
+        }
+    }
+
+    /// Snapshot of the lifetime totals at the time the last daily report was sent.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReportBaseline {
+        pub date: String,
+        pub total_fish_caught: u64,
+        pub total_feeds: u64,
+        pub total_runtime_seconds: u64,
+        pub total_errors: u64,
+        pub sessions_completed: u64,
     }
 
     impl Default for LifetimeStats {
@@ -208,6 +1031,13 @@ mod config {
                 average_fish_per_hour: 0.0,
                 total_feeds: 0,
                 uptime_percentage: 100.0,
+                total_errors: 0,
+                daily_report_baseline: None,
+                session_history: Vec::new(),
+                days_active: 0,
+                current_day_streak: 0,
+                best_day_streak: 0,
+                last_active_day: None,
             }
         }
     }
@@ -257,19 +1087,155 @@ mod config {
             self.save().ok();
         }
 
-        pub fn complete_session(&mut self, session_fish: u64) {
+        pub fn complete_session(&mut self, record: SessionRecord) {
             self.sessions_completed += 1;
-            if session_fish > self.best_session_fish {
-                self.best_session_fish = session_fish;
+            self.mark_day_active(Local::now().date_naive());
+            if record.fish > self.best_session_fish {
+                self.best_session_fish = record.fish;
+            }
+            self.session_history.push(record);
+            if self.session_history.len() > MAX_SESSION_HISTORY {
+                let excess = self.session_history.len() - MAX_SESSION_HISTORY;
+                self.session_history.drain(..excess);
             }
             self.save().ok();
         }
 
+        /// Counts `today` as an active day, extending the streak when the previous
+        /// active day was yesterday and restarting it after a gap.
+        fn mark_day_active(&mut self, today: chrono::NaiveDate) {
+            let last = self
+                .last_active_day
+                .as_deref()
+                .and_then(|day| chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").ok());
+            match last {
+                Some(day) if day == today => return,
+                Some(day) if day == today - chrono::Duration::days(1) => {
+                    self.current_day_streak += 1;
+                }
+                _ => self.current_day_streak = 1,
+            }
+            self.days_active += 1;
+            self.best_day_streak = self.best_day_streak.max(self.current_day_streak);
+            self.last_active_day = Some(today.format("%Y-%m-%d").to_string());
+        }
+
+        /// Average catch over the days the bot actually ran.
+        pub fn fish_per_active_day(&self) -> f32 {
+            if self.days_active == 0 {
+                return 0.0;
+            }
+            self.total_fish_caught as f32 / self.days_active as f32
+        }
+
+        /// Fish caught on each of the last `days` local calendar days, oldest first.
+        /// Days without sessions are included with a count of zero.
+        pub fn fish_per_day(&self, days: u32) -> Vec<(chrono::NaiveDate, u64)> {
+            let today = Local::now().date_naive();
+            let mut totals: Vec<(chrono::NaiveDate, u64)> = (0..days as i64)
+                .rev()
+                .map(|offset| (today - chrono::Duration::days(offset), 0))
+                .collect();
+
+            for record in &self.session_history {
+                let Ok(started) = chrono::DateTime::parse_from_rfc3339(&record.started_at) else {
+                    continue;
+                };
+                let day = started.with_timezone(&Local).date_naive();
+                if let Some((_, fish)) = totals.iter_mut().find(|(date, _)| *date == day) {
+                    *fish += record.fish;
+                }
+            }
+
+            totals
+        }
+
+        /// Writes the lifetime aggregates followed by one row per recorded session.
+        pub fn export_csv(&self, path: &std::path::Path) -> Result<()> {
+            fn format_runtime(seconds: u64) -> String {
+                format!(
+                    "{:02}:{:02}:{:02}",
+                    seconds / 3600,
+                    (seconds % 3600) / 60,
+                    seconds % 60
+                )
+            }
+
+            let mut writer = csv::Writer::from_path(path)?;
+            writer.write_record(["date", "fish", "feeds", "runtime", "best streak", "errors"])?;
+
+            let best_streak = self
+                .session_history
+                .iter()
+                .map(|record| record.best_streak)
+                .max()
+                .unwrap_or(0);
+            writer.write_record([
+                "All time".to_string(),
+                self.total_fish_caught.to_string(),
+                self.total_feeds.to_string(),
+                format_runtime(self.total_runtime_seconds),
+                best_streak.to_string(),
+                self.total_errors.to_string(),
+            ])?;
+
+            for record in &self.session_history {
+                let date = chrono::DateTime::parse_from_rfc3339(&record.started_at)
+                    .map(|date| {
+                        date.with_timezone(&Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                    })
+                    .unwrap_or_else(|_| record.started_at.clone());
+                writer.write_record([
+                    date,
+                    record.fish.to_string(),
+                    record.feeds.to_string(),
+                    format_runtime(record.runtime_seconds),
+                    record.best_streak.to_string(),
+                    record.errors.to_string(),
+                ])?;
+            }
+
+            writer.flush()?;
+            Ok(())
+        }
+
         pub fn add_feed(&mut self) {
             self.total_feeds += 1;
             self.save().ok();
         }
 
+        pub fn add_error(&mut self) {
+            self.total_errors += 1;
+            self.save().ok();
+        }
+
+        pub fn snapshot_baseline(&self, date: String) -> ReportBaseline {
+            ReportBaseline {
+                date,
+                total_fish_caught: self.total_fish_caught,
+                total_feeds: self.total_feeds,
+                total_runtime_seconds: self.total_runtime_seconds,
+                total_errors: self.total_errors,
+                sessions_completed: self.sessions_completed,
+            }
+        }
+
+        /// Returns the (fish, feeds, runtime seconds, errors, sessions) gained since `baseline`.
+        pub fn deltas_since(&self, baseline: &ReportBaseline) -> (u64, u64, u64, u64, u64) {
+            (
+                self.total_fish_caught
+                    .saturating_sub(baseline.total_fish_caught),
+                self.total_feeds.saturating_sub(baseline.total_feeds),
+                self.total_runtime_seconds
+                    .saturating_sub(baseline.total_runtime_seconds),
+                self.total_errors.saturating_sub(baseline.total_errors),
+                self.sessions_completed
+                    .saturating_sub(baseline.sessions_completed),
+            )
+        }
+
         fn update_calculations(&mut self) {
             if self.total_runtime_seconds > 0 {
                 self.average_fish_per_hour =
@@ -277,20 +1243,122 @@ mod config {
             }
         }
     }
-}
 
-// ===== DETECTION MODULE =====
-mod detection {
-    use super::*;
-    use config::Region;
-    use image::RgbaImage;
-    use rayon::prelude::*;
-    use screenshots::Screen;
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-    #[derive(Debug, Clone, Copy)]
-    pub struct Color {
-        pub r: u8,
-        pub g: u8,
+        #[test]
+        fn parses_comma_separated_xywh() {
+            assert_eq!(
+                Region::parse_xywh("10,-20,300,40").unwrap(),
+                Region::new(10, -20, 300, 40)
+            );
+        }
+
+        #[test]
+        fn parses_whitespace_separated_xywh() {
+            assert_eq!(
+                Region::parse_xywh("  10 20\t300\n40 ").unwrap(),
+                Region::new(10, 20, 300, 40)
+            );
+            assert_eq!(
+                Region::parse_xywh("10, 20, 300, 40").unwrap(),
+                Region::new(10, 20, 300, 40)
+            );
+        }
+
+        #[test]
+        fn rejects_the_wrong_number_of_values() {
+            assert!(Region::parse_xywh("10,20,300").is_err());
+            assert!(Region::parse_xywh("10,20,300,40,5").is_err());
+            assert!(Region::parse_xywh("").is_err());
+        }
+
+        #[test]
+        fn rejects_negative_and_out_of_range_sizes() {
+            assert!(Region::parse_xywh("10,20,-300,40").is_err());
+            assert!(Region::parse_xywh("10,20,300,-40").is_err());
+            assert!(Region::parse_xywh("10,20,0,40").is_err());
+            assert!(Region::parse_xywh("10,20,8001,40").is_err());
+            assert!(Region::parse_xywh("10,20,300,8001").is_err());
+            assert!(Region::parse_xywh("99999999999,20,300,40").is_err());
+            assert!(Region::parse_xywh("10,20,8000,8000").is_ok());
+        }
+
+        #[test]
+        fn xywh_round_trips() {
+            let original = Region::new(-1920, 45, 768, 546);
+            assert_eq!(Region::parse_xywh(&original.to_xywh()).unwrap(), original);
+        }
+
+        #[test]
+        fn paste_keeps_the_inset() {
+            let mut target = Region {
+                inset: 6,
+                ..Region::new(0, 0, 50, 50)
+            };
+            target.apply_xywh("10,20,300,40").unwrap();
+            assert_eq!(
+                target,
+                Region {
+                    inset: 6,
+                    ..Region::new(10, 20, 300, 40)
+                }
+            );
+
+            assert!(target.apply_xywh("garbage").is_err());
+            assert_eq!(target.to_xywh(), "10,20,300,40");
+        }
+
+        fn bite_time(lure: f32) -> Duration {
+            BotConfig {
+                rod_lure_value: lure,
+                ..BotConfig::default()
+            }
+            .calculate_max_bite_time()
+        }
+
+        #[test]
+        fn bite_time_is_continuous_across_lure_one() {
+            assert_eq!(bite_time(1.0), Duration::from_secs(65));
+
+            let below = bite_time(0.999).as_secs_f32();
+            let above = bite_time(1.001).as_secs_f32();
+            assert!((below - 65.0).abs() <= 0.2, "below 1.0: {}", below);
+            assert!((above - 65.0).abs() <= 0.2, "above 1.0: {}", above);
+            assert!(below > 65.0 && above < 65.0);
+        }
+
+        #[test]
+        fn bite_time_is_clamped_at_both_ends() {
+            assert_eq!(bite_time(0.0), Duration::from_secs(180));
+            assert_eq!(bite_time(-5.0), Duration::from_secs(180));
+            assert_eq!(bite_time(f32::NEG_INFINITY), Duration::from_secs(180));
+            assert_eq!(bite_time(4.0), Duration::from_secs(10));
+            assert_eq!(bite_time(100.0), Duration::from_secs(10));
+            assert_eq!(bite_time(f32::INFINITY), Duration::from_secs(10));
+        }
+
+        #[test]
+        fn nan_lure_uses_the_neutral_bite_time() {
+            assert_eq!(bite_time(f32::NAN), Duration::from_secs(65));
+        }
+    }
+}
+
+// ===== DETECTION MODULE =====
+mod detection {
+    use super::*;
+    use config::Region;
+    use image::RgbaImage;
+    use rayon::prelude::*;
+    use screenshots::Screen;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Color {
+        pub r: u8,
+        pub g: u8,
         pub b: u8,
     }
 
@@ -314,99 +1382,562 @@ mod detection {
         }
 
         pub fn distance_squared(&self, other: &[u8]) -> u32 {
-            let dr = (self.r as i32 - other[0] as i32) as u32;
-            let dg = (self.g as i32 - other[1] as i32) as u32;
-            let db = (self.b as i32 - other[2] as i32) as u32;
+            let dr = (self.r as i32 - other[0] as i32).unsigned_abs();
+            let dg = (self.g as i32 - other[1] as i32).unsigned_abs();
+            let db = (self.b as i32 - other[2] as i32).unsigned_abs();
             dr * dr + dg * dg + db * db
         }
     }
 
+    /// Where the detector gets its pixels from.
+    pub trait FrameSource: Send + Sync {
+        fn capture_area(&self, region: Region) -> Result<RgbaImage>;
+
+        fn capture_full(&self) -> Result<RgbaImage>;
+
+        /// False while the underlying display is gone, e.g. a monitor was unplugged.
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    /// Live frames from one of the connected displays.
+    pub struct ScreenFrameSource {
+        pub index: usize,
+    }
+
+    impl ScreenFrameSource {
+        fn screen(&self) -> Result<Screen> {
+            Screen::all()?
+                .get(self.index)
+                .copied()
+                .ok_or_else(|| anyhow!("Display {} is not connected", self.index))
+        }
+    }
+
+    impl FrameSource for ScreenFrameSource {
+        fn capture_area(&self, region: Region) -> Result<RgbaImage> {
+            let image =
+                self.screen()?
+                    .capture_area(region.x, region.y, region.width, region.height)?;
+
+            RgbaImage::from_raw(region.width, region.height, image.to_vec())
+                .ok_or_else(|| anyhow!("Failed to create image"))
+        }
+
+        fn capture_full(&self) -> Result<RgbaImage> {
+            let screen = self.screen()?;
+            let image = screen.capture()?;
+
+            RgbaImage::from_raw(
+                screen.display_info.width,
+                screen.display_info.height,
+                image.to_vec(),
+            )
+            .ok_or_else(|| anyhow!("Failed to create full screenshot"))
+        }
+
+        fn is_available(&self) -> bool {
+            self.screen().is_ok()
+        }
+    }
+
+    /// Offsets each region by the Roblox window's client-area origin before
+    /// capturing, so regions keep working when the window moves.
+    pub struct WindowRelativeFrameSource {
+        inner: Box<dyn FrameSource>,
+    }
+
+    impl FrameSource for WindowRelativeFrameSource {
+        fn capture_area(&self, region: Region) -> Result<RgbaImage> {
+            let (x, y) =
+                roblox_client_origin().ok_or_else(|| anyhow!("Roblox window not found"))?;
+            self.inner.capture_area(Region {
+                x: region.x + x,
+                y: region.y + y,
+                ..region
+            })
+        }
+
+        fn capture_full(&self) -> Result<RgbaImage> {
+            self.inner.capture_full()
+        }
+
+        fn is_available(&self) -> bool {
+            self.inner.is_available()
+        }
+    }
+
+    /// Desktop position of the top-left corner of the Roblox window's client area.
+    #[cfg(windows)]
+    pub fn roblox_client_origin() -> Option<(i32, i32)> {
+        use winapi::shared::windef::POINT;
+        use winapi::um::winuser::{ClientToScreen, FindWindowW};
+
+        let title: Vec<u16> = "Roblox".encode_utf16().chain(Some(0)).collect();
+        unsafe {
+            let hwnd = FindWindowW(std::ptr::null(), title.as_ptr());
+            if hwnd.is_null() {
+                return None;
+            }
+            let mut origin = POINT { x: 0, y: 0 };
+            (ClientToScreen(hwnd, &mut origin) != 0).then_some((origin.x, origin.y))
+        }
+    }
+
+    #[cfg(not(windows))]
+    pub fn roblox_client_origin() -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Desktop position of a point given in region coordinates, offset the same
+    /// way captures are, so clicks land where a region is.
+    pub fn region_point_on_desktop(config: &config::BotConfig, (x, y): (i32, i32)) -> (i32, i32) {
+        let window = config
+            .window_relative_regions
+            .then(roblox_client_origin)
+            .flatten()
+            .unwrap_or((0, 0));
+        let display = Screen::all()
+            .ok()
+            .and_then(|screens| screens.get(config.screen_index).copied())
+            .map(|screen| (screen.display_info.x, screen.display_info.y))
+            .unwrap_or((0, 0));
+        (x + window.0 + display.0, y + window.1 + display.1)
+    }
+
+    /// Serves every capture from one saved screenshot, with regions in the
+    /// image's own pixel coordinates. Test fixtures only.
+    #[cfg(test)]
+    pub struct StaticFrameSource {
+        image: RgbaImage,
+        captures: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[cfg(test)]
+    impl StaticFrameSource {
+        pub fn open(path: &std::path::Path) -> Result<Self> {
+            Ok(Self::from_image(image::open(path)?.to_rgba8()))
+        }
+
+        pub fn from_image(image: RgbaImage) -> Self {
+            Self {
+                image,
+                captures: Arc::default(),
+            }
+        }
+
+        /// Counts every capture taken, for checking how often the detector reads.
+        pub fn captures(&self) -> Arc<std::sync::atomic::AtomicUsize> {
+            self.captures.clone()
+        }
+    }
+
+    #[cfg(test)]
+    impl FrameSource for StaticFrameSource {
+        fn capture_area(&self, region: Region) -> Result<RgbaImage> {
+            self.captures
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let full = Region::new(0, 0, self.image.width(), self.image.height());
+            if region.x < 0 || region.y < 0 || !AdvancedDetector::contains(&full, &region) {
+                return Err(anyhow!("Region outside frame image"));
+            }
+
+            Ok(image::imageops::crop_imm(
+                &self.image,
+                region.x as u32,
+                region.y as u32,
+                region.width,
+                region.height,
+            )
+            .to_image())
+        }
+
+        fn capture_full(&self) -> Result<RgbaImage> {
+            Ok(self.image.clone())
+        }
+    }
+
+    /// The configured display, offset to the Roblox window when regions are
+    /// window-relative.
+    pub fn frame_source_for(config: &config::BotConfig) -> Box<dyn FrameSource> {
+        if config.window_relative_regions {
+            if cfg!(windows) {
+                return Box::new(WindowRelativeFrameSource {
+                    inner: Box::new(ScreenFrameSource {
+                        index: config.screen_index,
+                    }),
+                });
+            }
+            log::warn!("Window-relative regions need Windows, using desktop coordinates");
+        }
+        Box::new(ScreenFrameSource {
+            index: config.screen_index,
+        })
+    }
+
+    /// A single capture covering every active region, cropped on demand.
+    struct SharedFrame {
+        bounds: Region,
+        image: RgbaImage,
+        captured_at: Instant,
+    }
+
     pub struct AdvancedDetector {
         cache: Arc<RwLock<HashMap<String, (RgbaImage, Instant)>>>,
         cache_duration: Duration,
         tolerance: u8,
+        low_light_boost: u8,
+        low_light_brightness: u8,
+        min_match_pixels: u32,
+        min_cluster_pixels: u32,
+        min_match_fraction: Option<f32>,
+        downsample: u32,
         advanced_mode: bool,
+        last_match_count: std::sync::atomic::AtomicU32,
+        previous_frame: RwLock<Option<RgbaImage>>,
+        active_regions: RwLock<Vec<Region>>,
+        frame: RwLock<Option<SharedFrame>>,
+        source: Box<dyn FrameSource>,
     }
 
     impl AdvancedDetector {
-        pub fn new(cache_duration_ms: u64, tolerance: u8, advanced_mode: bool) -> Self {
+        pub fn new(
+            cache_duration_ms: u64,
+            tolerance: u8,
+            min_match_pixels: u32,
+            min_cluster_pixels: u32,
+            advanced_mode: bool,
+            source: Box<dyn FrameSource>,
+        ) -> Self {
             Self {
                 cache: Arc::new(RwLock::new(HashMap::new())),
                 cache_duration: Duration::from_millis(cache_duration_ms),
                 tolerance,
+                low_light_boost: 0,
+                low_light_brightness: 0,
+                min_match_pixels: min_match_pixels.max(1),
+                min_cluster_pixels: min_cluster_pixels.max(1),
+                min_match_fraction: None,
+                downsample: 1,
                 advanced_mode,
+                last_match_count: std::sync::atomic::AtomicU32::new(0),
+                previous_frame: RwLock::new(None),
+                active_regions: RwLock::new(Vec::new()),
+                frame: RwLock::new(None),
+                source,
+            }
+        }
+
+        /// Widens the color tolerance by up to `boost` as the region's mean
+        /// brightness falls below `below_brightness`, for night-time lighting.
+        pub fn with_low_light_boost(mut self, boost: u8, below_brightness: u8) -> Self {
+            self.low_light_boost = boost;
+            self.low_light_brightness = below_brightness;
+            self
+        }
+
+        /// Samples every `step`th pixel in each axis when matching colors. Match
+        /// counts are scaled back up by `step²`, so pixel thresholds keep their meaning.
+        pub fn with_downsample(mut self, step: u32) -> Self {
+            self.downsample = step.max(1);
+            self
+        }
+
+        /// Replaces the absolute match and cluster thresholds with `fraction` of
+        /// each checked region's pixels.
+        pub fn with_match_fraction(mut self, fraction: Option<f32>) -> Self {
+            self.min_match_fraction = fraction.filter(|f| *f > 0.0);
+            self
+        }
+
+        /// Pixels a check of `image` needs: `absolute`, or the configured fraction
+        /// of the full-resolution region area.
+        fn required_pixels(&self, absolute: u32, image: &RgbaImage) -> usize {
+            match self.min_match_fraction {
+                Some(fraction) => {
+                    let area = (image.width() * image.height()) as usize * self.sample_weight();
+                    ((area as f32 * fraction).ceil() as usize).max(1)
+                }
+                None => absolute as usize,
             }
         }
 
         pub fn detect_color(&self, region: Region, target: &Color) -> Result<bool> {
+            Ok(self.detect_colors(region, std::slice::from_ref(target))?[0])
+        }
+
+        /// Checks one capture of `region` against every target, in order. The
+        /// recorded match count is the first target's.
+        pub fn detect_colors(&self, region: Region, targets: &[Color]) -> Result<Vec<bool>> {
+            let screenshot = self.downsampled(self.get_screenshot(region)?);
+            let tolerance = self.effective_tolerance(&screenshot);
+
+            let mut results = Vec::with_capacity(targets.len());
+            for (index, target) in targets.iter().enumerate() {
+                let (found, matched) = if self.advanced_mode {
+                    self.advanced_color_detection(&screenshot, target, tolerance)
+                } else {
+                    self.basic_color_detection(&screenshot, target, tolerance)
+                };
+                if index == 0 {
+                    self.record_match_count(matched);
+                }
+                results.push(found);
+            }
+            Ok(results)
+        }
+
+        fn downsampled(&self, image: RgbaImage) -> RgbaImage {
+            let step = self.downsample;
+            if step <= 1 {
+                return image;
+            }
+
+            let width = image.width().div_ceil(step);
+            let height = image.height().div_ceil(step);
+            RgbaImage::from_fn(width, height, |x, y| *image.get_pixel(x * step, y * step))
+        }
+
+        /// How many full-resolution pixels each sampled pixel stands for.
+        fn sample_weight(&self) -> usize {
+            (self.downsample * self.downsample) as usize
+        }
+
+        fn effective_tolerance(&self, image: &RgbaImage) -> u32 {
+            let base = self.tolerance as u32;
+            if self.low_light_boost == 0 || self.low_light_brightness == 0 {
+                return base;
+            }
+
+            let threshold = self.low_light_brightness as f32;
+            let darkness = (threshold - Self::mean_brightness(image)).max(0.0) / threshold;
+            base + (self.low_light_boost as f32 * darkness).round() as u32
+        }
+
+        /// Average of the RGB channels over the whole image, 0-255.
+        fn mean_brightness(image: &RgbaImage) -> f32 {
+            let pixel_count = (image.width() * image.height()) as u64;
+            if pixel_count == 0 {
+                return 0.0;
+            }
+
+            let total: u64 = image
+                .as_raw()
+                .par_chunks(4)
+                .map(|p| p[0] as u64 + p[1] as u64 + p[2] as u64)
+                .sum();
+            total as f32 / (pixel_count * 3) as f32
+        }
+
+        /// Number of pixels that matched in the most recent color detection.
+        pub fn last_match_count(&self) -> u32 {
+            self.last_match_count
+                .load(std::sync::atomic::Ordering::Relaxed)
+        }
+
+        fn record_match_count(&self, count: usize) {
+            self.last_match_count.store(
+                count.min(u32::MAX as usize) as u32,
+                std::sync::atomic::Ordering::Relaxed,
+            );
+        }
+
+        /// Compares the region against the previous frame and reports whether the mean
+        /// absolute per-channel difference exceeds `threshold`.
+        pub fn detect_motion(&self, region: Region, threshold: f32) -> Result<bool> {
             let screenshot = self.get_screenshot(region)?;
+            let mut previous = self.previous_frame.write();
 
-            if self.advanced_mode {
-                self.advanced_color_detection(&screenshot, target)
-            } else {
-                self.basic_color_detection(&screenshot, target)
+            let delta = match previous.as_ref() {
+                Some(prev) if prev.dimensions() == screenshot.dimensions() => {
+                    Some(Self::mean_frame_delta(prev, &screenshot))
+                }
+                _ => None,
+            };
+
+            *previous = Some(screenshot);
+            Ok(delta.is_some_and(|delta| delta > threshold))
+        }
+
+        /// Forgets the stored frame so the next motion check starts a new baseline.
+        pub fn reset_motion_baseline(&self) {
+            *self.previous_frame.write() = None;
+        }
+
+        /// True when more than `ratio` (0..1) of the pixels are near-black, as happens
+        /// while the display sleeps or the game window is minimized.
+        pub fn is_blank(image: &RgbaImage, ratio: f32) -> bool {
+            let pixel_count = (image.width() * image.height()) as usize;
+            if pixel_count == 0 {
+                return true;
             }
+
+            let dark = image
+                .as_raw()
+                .par_chunks(4)
+                .filter(|p| p[0] as u32 + p[1] as u32 + p[2] as u32 <= 30)
+                .count();
+            dark as f32 / pixel_count as f32 > ratio
         }
 
-        fn basic_color_detection(&self, image: &RgbaImage, target: &Color) -> Result<bool> {
-            let tolerance = self.tolerance as u32 * 3;
-            let pixels: Vec<_> = image.pixels().collect();
+        /// Share (0..1) of the pixels within `tolerance` per channel of `target`.
+        pub fn color_coverage(image: &RgbaImage, target: &Color, tolerance: u8) -> f32 {
+            let pixel_count = (image.width() * image.height()) as usize;
+            if pixel_count == 0 {
+                return 0.0;
+            }
 
-            Ok(pixels
-                .par_iter()
-                .any(|pixel| target.distance(&pixel.0) <= tolerance))
+            let tolerance = tolerance as u32 * 3;
+            let matched = image
+                .as_raw()
+                .par_chunks(4)
+                .filter(|p| target.distance(p) <= tolerance)
+                .count();
+            matched as f32 / pixel_count as f32
+        }
+
+        fn mean_frame_delta(previous: &RgbaImage, current: &RgbaImage) -> f32 {
+            let pixel_count = (current.width() * current.height()) as u64;
+            if pixel_count == 0 {
+                return 0.0;
+            }
+
+            let total: u64 = previous
+                .as_raw()
+                .par_chunks(4)
+                .zip(current.as_raw().par_chunks(4))
+                .map(|(a, b)| {
+                    (a[0] as i32 - b[0] as i32).unsigned_abs() as u64
+                        + (a[1] as i32 - b[1] as i32).unsigned_abs() as u64
+                        + (a[2] as i32 - b[2] as i32).unsigned_abs() as u64
+                })
+                .sum();
+
+            total as f32 / (pixel_count * 3) as f32
         }
 
-        fn advanced_color_detection(&self, image: &RgbaImage, target: &Color) -> Result<bool> {
-            let tolerance_squared = (self.tolerance as u32 * 3).pow(2);
+        fn basic_color_detection(
+            &self,
+            image: &RgbaImage,
+            target: &Color,
+            tolerance: u32,
+        ) -> (bool, usize) {
+            let tolerance = tolerance * 3;
             let pixels: Vec<_> = image.pixels().collect();
 
-            // Use more sophisticated detection with clustering
-            let matches: Vec<_> = pixels
+            // A single stray anti-aliased pixel is not a bite
+            let matched = pixels
                 .par_iter()
-                .enumerate()
-                .filter(|(_, pixel)| target.distance_squared(&pixel.0) <= tolerance_squared)
-                .map(|(i, _)| i)
+                .filter(|pixel| target.distance(&pixel.0) <= tolerance)
+                .count()
+                * self.sample_weight();
+
+            (
+                matched >= self.required_pixels(self.min_match_pixels, image),
+                matched,
+            )
+        }
+
+        /// Requires the matching pixels to form one connected blob of at least
+        /// `min_cluster_pixels`, so scattered noise across the region never counts.
+        fn advanced_color_detection(
+            &self,
+            image: &RgbaImage,
+            target: &Color,
+            tolerance: u32,
+        ) -> (bool, usize) {
+            let tolerance_squared = (tolerance * 3).pow(2);
+            let mask: Vec<bool> = image
+                .as_raw()
+                .par_chunks(4)
+                .map(|pixel| target.distance_squared(pixel) <= tolerance_squared)
                 .collect();
+            let matched = mask.iter().filter(|&&m| m).count() * self.sample_weight();
 
-            if matches.is_empty() {
-                return Ok(false);
-            }
+            let largest =
+                Self::largest_cluster(&mask, image.width() as usize) * self.sample_weight();
+            (
+                largest >= self.required_pixels(self.min_cluster_pixels, image),
+                matched,
+            )
+        }
 
-            // Check for clustering - reduces false positives
-            let cluster_threshold = 5; // pixels
-            let mut clusters = 0;
-            let width = image.width() as usize;
+        /// Size of the largest 8-connected group of set cells in a row-major mask.
+        fn largest_cluster(mask: &[bool], width: usize) -> usize {
+            if width == 0 {
+                return 0;
+            }
+            let height = mask.len() / width;
+            let mut seen = vec![false; mask.len()];
+            let mut stack = Vec::new();
+            let mut largest = 0;
 
-            for &idx in &matches {
-                let (x, y) = (idx % width, idx / width);
-                let nearby_matches = matches
-                    .iter()
-                    .filter(|&&other_idx| {
-                        let (ox, oy) = (other_idx % width, other_idx / width);
-                        let dx = (x as i32 - ox as i32).abs();
-                        let dy = (y as i32 - oy as i32).abs();
-                        dx <= cluster_threshold && dy <= cluster_threshold
-                    })
-                    .count();
+            for start in 0..mask.len() {
+                if !mask[start] || seen[start] {
+                    continue;
+                }
 
-                if nearby_matches >= 3 {
-                    clusters += 1;
-                    if clusters >= 2 {
-                        return Ok(true);
+                seen[start] = true;
+                stack.push(start);
+                let mut size = 0;
+                while let Some(idx) = stack.pop() {
+                    size += 1;
+                    let (x, y) = (idx % width, idx / width);
+                    for ny in y.saturating_sub(1)..=(y + 1).min(height - 1) {
+                        for nx in x.saturating_sub(1)..=(x + 1).min(width - 1) {
+                            let neighbor = ny * width + nx;
+                            if mask[neighbor] && !seen[neighbor] {
+                                seen[neighbor] = true;
+                                stack.push(neighbor);
+                            }
+                        }
                     }
                 }
+                largest = largest.max(size);
             }
 
-            Ok(clusters > 0)
+            largest
+        }
+
+        /// Registers the regions the bot scans so they can share one capture per frame.
+        pub fn set_active_regions(&self, regions: &[Region]) {
+            *self.active_regions.write() = regions.to_vec();
+            *self.frame.write() = None;
         }
 
         pub fn get_screenshot(&self, region: Region) -> Result<RgbaImage> {
+            let region = region.inset_applied();
+            let now = Instant::now();
+
+            // Serve from the shared frame while it is fresh
+            if let Some(image) = self.crop_from_frame(region, now) {
+                return Ok(image);
+            }
+
+            // Active regions refresh the shared frame covering all of them
+            let active_regions = self.active_regions.read().clone();
+            if active_regions
+                .iter()
+                .any(|active| Self::contains(active, &region))
+            {
+                let bounds = Self::bounding_box(&active_regions);
+                self.refresh_frame(bounds)?;
+                // Just captured, so it is served whatever the cache duration
+                let fresh = self
+                    .frame
+                    .read()
+                    .as_ref()
+                    .and_then(|frame| Self::crop_frame(frame, region));
+                if let Some(image) = fresh {
+                    return Ok(image);
+                }
+            }
+
             let cache_key = format!(
                 "{},{},{},{}",
                 region.x, region.y, region.width, region.height
             );
-            let now = Instant::now();
 
             // Check cache first
             {
@@ -419,94 +1950,354 @@ mod detection {
             }
 
             // Take new screenshot
-            let screens = Screen::all()?;
-            if screens.is_empty() {
-                return Err(anyhow!("No screens found"));
-            }
-
-            let image = screens[0].capture_area(region.x, region.y, region.width, region.height)?;
-
-            let rgba_image = RgbaImage::from_raw(region.width, region.height, image.to_vec())
-                .ok_or_else(|| anyhow!("Failed to create image"))?;
+            let rgba_image = self.source.capture_area(region)?;
 
             // Update cache
             {
                 let mut cache = self.cache.write();
                 cache.insert(cache_key, (rgba_image.clone(), now));
 
-                // Clean old entries
+                // Entries past the cache duration are never served again
                 cache.retain(|_, (_, timestamp)| {
-                    now.duration_since(*timestamp) < Duration::from_secs(10)
+                    now.duration_since(*timestamp) < self.cache_duration
                 });
             }
 
             Ok(rgba_image)
         }
 
-        pub fn take_full_screenshot(&self) -> Result<RgbaImage> {
-            let screens = Screen::all()?;
-            if screens.is_empty() {
-                return Err(anyhow!("No screens found"));
+        fn crop_from_frame(&self, region: Region, now: Instant) -> Option<RgbaImage> {
+            let frame = self.frame.read();
+            let frame = frame.as_ref()?;
+            if now.duration_since(frame.captured_at) >= self.cache_duration {
+                return None;
             }
+            Self::crop_frame(frame, region)
+        }
 
-            let screen = &screens[0];
-            let image = screen.capture()?;
+        fn crop_frame(frame: &SharedFrame, region: Region) -> Option<RgbaImage> {
+            if !Self::contains(&frame.bounds, &region) {
+                return None;
+            }
 
-            RgbaImage::from_raw(
-                screen.display_info.width,
-                screen.display_info.height,
-                image.to_vec(),
+            Some(
+                image::imageops::crop_imm(
+                    &frame.image,
+                    (region.x - frame.bounds.x) as u32,
+                    (region.y - frame.bounds.y) as u32,
+                    region.width,
+                    region.height,
+                )
+                .to_image(),
             )
-            .ok_or_else(|| anyhow!("Failed to create full screenshot"))
         }
-    }
-}
 
-// ===== INPUT MODULE =====
-mod input {
-    use super::*;
-    use enigo::{Enigo, Settings};
+        fn refresh_frame(&self, bounds: Region) -> Result<()> {
+            let image = self.source.capture_area(bounds)?;
+            *self.frame.write() = Some(SharedFrame {
+                bounds,
+                image,
+                captured_at: Instant::now(),
+            });
+            Ok(())
+        }
 
-    #[cfg(windows)]
-    use winapi::um::winuser::{
-        GetCursorPos, MapVirtualKeyW, SendInput, INPUT, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT,
-        KEYEVENTF_KEYUP, KEYEVENTF_SCANCODE, MAPVK_VK_TO_VSC, MOUSEEVENTF_LEFTDOWN,
-        MOUSEEVENTF_LEFTUP, MOUSEINPUT,
-    };
+        fn contains(outer: &Region, inner: &Region) -> bool {
+            inner.x >= outer.x
+                && inner.y >= outer.y
+                && inner.x + inner.width as i32 <= outer.x + outer.width as i32
+                && inner.y + inner.height as i32 <= outer.y + outer.height as i32
+        }
 
-    #[cfg(windows)]
-    use winapi::shared::windef::POINT;
+        fn bounding_box(regions: &[Region]) -> Region {
+            let left = regions.iter().map(|r| r.x).min().unwrap_or(0);
+            let top = regions.iter().map(|r| r.y).min().unwrap_or(0);
+            let right = regions
+                .iter()
+                .map(|r| r.x + r.width as i32)
+                .max()
+                .unwrap_or(0);
+            let bottom = regions
+                .iter()
+                .map(|r| r.y + r.height as i32)
+                .max()
+                .unwrap_or(0);
+            Region::new(
+                left,
+                top,
+                (right - left).max(0) as u32,
+                (bottom - top).max(0) as u32,
+            )
+        }
 
-    pub struct RobloxInputController {
-        #[cfg(not(windows))]
-        enigo: Enigo,
-        failsafe_enabled: bool,
-        last_action_time: Instant,
+        pub fn take_full_screenshot(&self) -> Result<RgbaImage> {
+            self.source.capture_full()
+        }
+
+        pub fn source_available(&self) -> bool {
+            self.source.is_available()
+        }
     }
 
-    impl RobloxInputController {
-        pub fn new(failsafe_enabled: bool) -> Self {
-            Self {
-                #[cfg(not(windows))]
-                enigo: Enigo::new(&Settings::default()).expect("Failed to create Enigo instance"),
-                failsafe_enabled,
-                last_action_time: Instant::now(),
-            }
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// 80x40 fixture: a solid 6x6 red blob near the top-left, 20 isolated red
+        /// pixels spaced three apart to its right, and a 6x4 yellow blob lower right.
+        fn fixture_detector(advanced: bool) -> AdvancedDetector {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/detection_frame.png");
+            AdvancedDetector::new(
+                0,
+                10,
+                10,
+                8,
+                advanced,
+                Box::new(StaticFrameSource::open(&path).unwrap()),
+            )
         }
 
-        fn check_failsafe(&mut self) -> Result<()> {
-            if !self.failsafe_enabled {
-                return Ok(());
-            }
+        const BLOB: (i32, i32, u32, u32) = (0, 0, 20, 20);
+        const NOISE: (i32, i32, u32, u32) = (28, 3, 20, 16);
+        const EMPTY: (i32, i32, u32, u32) = (0, 20, 20, 20);
 
-            // Check mouse position failsafe (top-left corner)
-            #[cfg(windows)]
-            unsafe {
-                let mut point = POINT { x: 0, y: 0 };
-                if GetCursorPos(&mut point) != 0 {
-                    if point.x < 5 && point.y < 5 {
-                        return Err(anyhow!("Failsafe triggered: mouse in top-left corner"));
-                    }
+        fn detect(detector: &AdvancedDetector, (x, y, w, h): (i32, i32, u32, u32)) -> (bool, u32) {
+            let found = detector
+                .detect_color(Region::new(x, y, w, h), &Color::RED_EXCLAMATION)
+                .unwrap();
+            (found, detector.last_match_count())
+        }
+
+        #[test]
+        fn basic_detection_counts_every_matching_pixel() {
+            let detector = fixture_detector(false);
+            assert_eq!(detect(&detector, BLOB), (true, 36));
+            assert_eq!(detect(&detector, NOISE), (true, 20));
+            assert_eq!(detect(&detector, EMPTY), (false, 0));
+        }
+
+        #[test]
+        fn clustering_detection_needs_a_connected_blob() {
+            let detector = fixture_detector(true);
+            assert_eq!(detect(&detector, BLOB), (true, 36));
+            assert_eq!(detect(&detector, NOISE), (false, 20));
+            assert_eq!(detect(&detector, EMPTY), (false, 0));
+        }
+
+        #[test]
+        fn detect_colors_checks_each_target_in_one_capture() {
+            let detector = fixture_detector(true);
+            let found = detector
+                .detect_colors(
+                    Region::new(50, 20, 20, 20),
+                    &[Color::YELLOW_CAUGHT, Color::RED_EXCLAMATION],
+                )
+                .unwrap();
+            assert_eq!(found, vec![true, false]);
+            assert_eq!(detector.last_match_count(), 24);
+        }
+
+        fn in_memory_detector() -> AdvancedDetector {
+            AdvancedDetector::new(
+                0,
+                10,
+                10,
+                8,
+                true,
+                Box::new(StaticFrameSource::from_image(RgbaImage::new(1, 1))),
+            )
+        }
+
+        /// 30x30 dark image with red wherever `red` says so.
+        fn image_with(red: impl Fn(u32, u32) -> bool) -> RgbaImage {
+            RgbaImage::from_fn(30, 30, |x, y| {
+                if red(x, y) {
+                    image::Rgba([241, 27, 28, 255])
+                } else {
+                    image::Rgba([20, 20, 20, 255])
+                }
+            })
+        }
+
+        #[test]
+        fn scattered_noise_is_not_a_cluster() {
+            // Every third pixel in both axes: 100 matches, none touching
+            let image = image_with(|x, y| x % 3 == 0 && y % 3 == 0);
+            let (found, matched) =
+                in_memory_detector().advanced_color_detection(&image, &Color::RED_EXCLAMATION, 10);
+            assert!(!found);
+            assert_eq!(matched, 100);
+        }
+
+        #[test]
+        fn solid_blob_is_a_cluster() {
+            let image = image_with(|x, y| (10..14).contains(&x) && (10..13).contains(&y));
+            let (found, matched) =
+                in_memory_detector().advanced_color_detection(&image, &Color::RED_EXCLAMATION, 10);
+            assert!(found);
+            assert_eq!(matched, 12);
+        }
+
+        #[test]
+        fn largest_cluster_joins_diagonal_neighbours() {
+            #[rustfmt::skip]
+            let mask = [
+                true,  false, false, false,
+                false, true,  false, true,
+                false, false, true,  true,
+            ];
+            assert_eq!(AdvancedDetector::largest_cluster(&mask, 4), 5);
+            assert_eq!(AdvancedDetector::largest_cluster(&[false; 6], 3), 0);
+            assert_eq!(AdvancedDetector::largest_cluster(&[], 0), 0);
+        }
+
+        #[test]
+        fn uncached_active_regions_take_one_capture_per_read() {
+            let source = StaticFrameSource::from_image(RgbaImage::new(80, 40));
+            let captures = source.captures();
+            let detector = AdvancedDetector::new(0, 10, 10, 8, false, Box::new(source));
+            let bite = Region::new(0, 0, 20, 20);
+            detector.set_active_regions(&[bite, Region::new(50, 20, 20, 20)]);
+
+            detector.get_screenshot(bite).unwrap();
+            detector.get_screenshot(bite).unwrap();
+            assert_eq!(captures.load(std::sync::atomic::Ordering::Relaxed), 2);
+        }
+
+        #[test]
+        fn static_frames_reject_regions_outside_the_image() {
+            let detector = fixture_detector(false);
+            assert!(detector
+                .detect_color(Region::new(70, 30, 20, 20), &Color::RED_EXCLAMATION)
+                .is_err());
+        }
+    }
+}
+
+// ===== INPUT MODULE =====
+mod input {
+    use super::*;
+    use enigo::{Enigo, Settings};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use clock::Clock;
+    use config::WindowsInputMethod;
+
+    #[cfg(windows)]
+    use winapi::um::winuser::{
+        FindWindowW, GetCursorPos, MapVirtualKeyW, PostMessageW, ScreenToClient, SendInput,
+        SetCursorPos, INPUT, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT, KEYEVENTF_KEYUP,
+        KEYEVENTF_SCANCODE, MAPVK_VK_TO_VSC, MK_LBUTTON, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP,
+        MOUSEINPUT, WM_KEYDOWN, WM_KEYUP, WM_LBUTTONDOWN, WM_LBUTTONUP,
+    };
+
+    #[cfg(windows)]
+    use winapi::shared::windef::{HWND, POINT};
+
+    /// Keys some controller has pressed and not yet released. Shared by every
+    /// controller instance, since the held state lives in the OS.
+    static HELD_KEYS: Mutex<Vec<char>> = Mutex::new(Vec::new());
+    /// Set between a left-button down and its up.
+    static MOUSE_HELD: AtomicBool = AtomicBool::new(false);
+
+    fn mark_key_held(key: char, held: bool) {
+        if let Ok(mut keys) = HELD_KEYS.lock() {
+            keys.retain(|&k| k != key);
+            if held {
+                keys.push(key);
+            }
+        }
+    }
+
+    pub struct RobloxInputController {
+        #[cfg(not(windows))]
+        enigo: Enigo,
+        failsafe_enabled: bool,
+        // Only Windows has more than one way to send input
+        #[cfg_attr(not(windows), allow(dead_code))]
+        method: WindowsInputMethod,
+        clock: Arc<dyn Clock>,
+        last_action_time: Instant,
+    }
+
+    impl RobloxInputController {
+        pub fn new(
+            failsafe_enabled: bool,
+            method: WindowsInputMethod,
+            clock: Arc<dyn Clock>,
+        ) -> Result<Self> {
+            Ok(Self {
+                #[cfg(not(windows))]
+                enigo: Enigo::new(&Settings::default())?,
+                failsafe_enabled,
+                method,
+                last_action_time: clock.now(),
+                clock,
+            })
+        }
+
+        #[cfg(windows)]
+        fn roblox_window() -> Result<HWND> {
+            let title: Vec<u16> = "Roblox".encode_utf16().chain(Some(0)).collect();
+            let hwnd = unsafe { FindWindowW(std::ptr::null(), title.as_ptr()) };
+            if hwnd.is_null() {
+                return Err(anyhow!("Roblox window not found for PostMessage input"));
+            }
+            Ok(hwnd)
+        }
+
+        /// Posts a left click to the Roblox window at a screen position; the real
+        /// cursor doesn't move.
+        #[cfg(windows)]
+        fn post_click_windows(&self, mut point: POINT) -> Result<()> {
+            let hwnd = Self::roblox_window()?;
+            unsafe {
+                ScreenToClient(hwnd, &mut point);
+                let position = ((point.y as u16 as u32) << 16 | point.x as u16 as u32) as isize;
+                PostMessageW(hwnd, WM_LBUTTONDOWN, MK_LBUTTON, position);
+                MOUSE_HELD.store(true, Ordering::SeqCst);
+                self.clock.sleep(Duration::from_millis(50));
+                PostMessageW(hwnd, WM_LBUTTONUP, 0, position);
+                MOUSE_HELD.store(false, Ordering::SeqCst);
+            }
+            Ok(())
+        }
+
+        #[cfg(windows)]
+        fn post_key_windows(&self, key_code: u8, key_up: bool) -> Result<()> {
+            let hwnd = Self::roblox_window()?;
+            unsafe {
+                let scan_code = MapVirtualKeyW(key_code as u32, MAPVK_VK_TO_VSC);
+                // Repeat count 1, scan code in bits 16-23; key-up also sets the
+                // previous-state and transition bits
+                let mut flags = 1 | (scan_code << 16);
+                if key_up {
+                    flags |= (1 << 30) | (1 << 31);
+                }
+                PostMessageW(
+                    hwnd,
+                    if key_up { WM_KEYUP } else { WM_KEYDOWN },
+                    key_code as usize,
+                    flags as isize,
+                );
+            }
+            Ok(())
+        }
+
+        fn check_failsafe(&mut self) -> Result<()> {
+            if !self.failsafe_enabled {
+                return Ok(());
+            }
+
+            // Check mouse position failsafe (top-left corner)
+            #[cfg(windows)]
+            unsafe {
+                let mut point = POINT { x: 0, y: 0 };
+                if GetCursorPos(&mut point) != 0 {
+                    if point.x < 5 && point.y < 5 {
+                        return Err(bot::BotError::FailsafeTriggered.into());
+                    }
                 }
             }
 
@@ -515,6 +2306,10 @@ mod input {
 
         #[cfg(windows)]
         fn send_key_windows(&self, key_code: u8, key_up: bool) -> Result<()> {
+            if self.method == WindowsInputMethod::PostMessage {
+                return self.post_key_windows(key_code, key_up);
+            }
+
             unsafe {
                 let scan_code = MapVirtualKeyW(key_code as u32, MAPVK_VK_TO_VSC) as u16;
                 let mut input = INPUT {
@@ -535,8 +2330,40 @@ mod input {
             Ok(())
         }
 
+        #[cfg(windows)]
+        fn send_mouse_up_windows(&self) {
+            if self.method == WindowsInputMethod::PostMessage {
+                if let Ok(hwnd) = Self::roblox_window() {
+                    unsafe { PostMessageW(hwnd, WM_LBUTTONUP, 0, 0) };
+                }
+                return;
+            }
+
+            unsafe {
+                let mut input_up = INPUT {
+                    type_: INPUT_MOUSE,
+                    u: std::mem::zeroed(),
+                };
+                *input_up.u.mi_mut() = MOUSEINPUT {
+                    dx: 0,
+                    dy: 0,
+                    mouseData: 0,
+                    dwFlags: MOUSEEVENTF_LEFTUP,
+                    time: 0,
+                    dwExtraInfo: 0,
+                };
+                SendInput(1, &mut input_up, std::mem::size_of::<INPUT>() as i32);
+            }
+        }
+
         #[cfg(windows)]
         fn send_mouse_click_windows(&self) -> Result<()> {
+            if self.method == WindowsInputMethod::PostMessage {
+                let mut cursor = POINT { x: 0, y: 0 };
+                unsafe { GetCursorPos(&mut cursor) };
+                return self.post_click_windows(cursor);
+            }
+
             unsafe {
                 // Mouse down
                 let mut input_down = INPUT {
@@ -567,13 +2394,149 @@ mod input {
                 };
 
                 SendInput(1, &mut input_down, std::mem::size_of::<INPUT>() as i32);
-                thread::sleep(Duration::from_millis(50));
+                MOUSE_HELD.store(true, Ordering::SeqCst);
+                self.clock.sleep(Duration::from_millis(50));
                 SendInput(1, &mut input_up, std::mem::size_of::<INPUT>() as i32);
+                MOUSE_HELD.store(false, Ordering::SeqCst);
+            }
+            Ok(())
+        }
+    }
+
+    /// Everything the bot needs from the keyboard and mouse. Compound actions
+    /// are built from the primitives so every backend performs the same sequence.
+    pub trait InputBackend: Send {
+        fn click(&mut self) -> Result<()>;
+
+        /// Clicks at an absolute screen position, then puts the cursor back where it was.
+        fn click_at(&mut self, x: i32, y: i32) -> Result<()>;
+
+        fn hold_key(&mut self, key: char, hold: Duration) -> Result<()>;
+
+        /// Lets go of any key or mouse button still held down, so nothing stays
+        /// pressed when the user takes over.
+        fn release_all(&mut self) -> Result<()>;
+
+        fn get_last_action_time(&self) -> Instant;
+
+        /// Time source for the pauses inside compound actions.
+        fn clock(&self) -> &dyn Clock;
+
+        /// Why this backend can't send input at all, if it can't.
+        fn init_error(&self) -> Option<&str> {
+            None
+        }
+
+        fn press_key(&mut self, key: char) -> Result<()> {
+            self.hold_key(key, Duration::from_millis(50))
+        }
+
+        /// Taps forward then back for the same short duration so the character
+        /// registers activity without drifting off the fishing spot.
+        fn anti_afk_nudge(&mut self, hold: Duration) -> Result<()> {
+            self.hold_key('w', hold)?;
+            self.clock().sleep(Duration::from_millis(100));
+            self.hold_key('s', hold)?;
+            Ok(())
+        }
+
+        fn run_sequence(&mut self, steps: &[config::InputStep]) -> Result<()> {
+            for step in steps {
+                match *step {
+                    config::InputStep::Click => self.click()?,
+                    config::InputStep::Tap(key) => self.press_key(key)?,
+                    config::InputStep::Hold { key, ms } => {
+                        self.hold_key(key, Duration::from_millis(ms))?
+                    }
+                    config::InputStep::Wait(ms) => self.clock().sleep(Duration::from_millis(ms)),
+                }
             }
             Ok(())
         }
+    }
+
+    #[cfg(target_os = "macos")]
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXIsProcessTrusted() -> u8;
+    }
+
+    /// Whether the OS lets this process send synthetic input. Only macOS gates
+    /// it, behind the Accessibility permission.
+    pub fn accessibility_trusted() -> bool {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            AXIsProcessTrusted() != 0
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            true
+        }
+    }
+
+    /// Opens Privacy & Security > Accessibility in System Settings.
+    pub fn open_accessibility_settings() -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            std::process::Command::new("open")
+                .arg(
+                    "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility",
+                )
+                .spawn()?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            Err(anyhow!("Accessibility settings only exist on macOS"))
+        }
+    }
+
+    /// Where the mouse cursor is right now, in screen coordinates.
+    pub fn cursor_position() -> Option<(i32, i32)> {
+        #[cfg(windows)]
+        unsafe {
+            let mut point = POINT { x: 0, y: 0 };
+            (GetCursorPos(&mut point) != 0).then_some((point.x, point.y))
+        }
+
+        #[cfg(not(windows))]
+        {
+            use enigo::Mouse;
+            Enigo::new(&Settings::default()).ok()?.location().ok()
+        }
+    }
+
+    /// Picks the backend for the current config: the real controller, or the
+    /// logging one when dry-run input is enabled.
+    pub fn backend_for(config: &config::BotConfig, clock: Arc<dyn Clock>) -> Box<dyn InputBackend> {
+        if config.input_dry_run {
+            Box::new(DryRunInput {
+                last_action_time: clock.now(),
+                clock,
+            })
+        } else {
+            match RobloxInputController::new(
+                config.failsafe_enabled,
+                config.windows_input_method,
+                clock.clone(),
+            ) {
+                Ok(controller) => Box::new(controller),
+                Err(e) => {
+                    log::error!("Failed to initialize input: {}", e);
+                    Box::new(UnavailableInput {
+                        error: e.to_string(),
+                        last_action_time: clock.now(),
+                        clock,
+                    })
+                }
+            }
+        }
+    }
 
-        pub fn click(&mut self) -> Result<()> {
+    impl InputBackend for RobloxInputController {
+        fn click(&mut self) -> Result<()> {
             self.check_failsafe()?;
 
             #[cfg(windows)]
@@ -587,16 +2550,54 @@ mod input {
                 self.enigo.button(Button::Left, Direction::Click)?;
             }
 
-            self.last_action_time = Instant::now();
+            self.last_action_time = self.clock.now();
+            Ok(())
+        }
+
+        /// Clicks at an absolute screen position, then puts the cursor back where it was.
+        fn click_at(&mut self, x: i32, y: i32) -> Result<()> {
+            self.check_failsafe()?;
+
+            #[cfg(windows)]
+            {
+                if self.method == WindowsInputMethod::PostMessage {
+                    self.post_click_windows(POINT { x, y })?;
+                } else {
+                    unsafe {
+                        let mut original = POINT { x: 0, y: 0 };
+                        let have_original = GetCursorPos(&mut original) != 0;
+                        SetCursorPos(x, y);
+                        self.clock.sleep(Duration::from_millis(30));
+                        self.send_mouse_click_windows()?;
+                        if have_original {
+                            SetCursorPos(original.x, original.y);
+                        }
+                    }
+                }
+            }
+
+            #[cfg(not(windows))]
+            {
+                use enigo::{Button, Coordinate, Direction, Mouse};
+                let original = self.enigo.location().ok();
+                self.enigo.move_mouse(x, y, Coordinate::Abs)?;
+                self.clock.sleep(Duration::from_millis(30));
+                self.enigo.button(Button::Left, Direction::Click)?;
+                if let Some((ox, oy)) = original {
+                    self.enigo.move_mouse(ox, oy, Coordinate::Abs)?;
+                }
+            }
+
+            self.last_action_time = self.clock.now();
             Ok(())
         }
 
-        pub fn press_key(&mut self, key: char) -> Result<()> {
+        fn hold_key(&mut self, key: char, hold: Duration) -> Result<()> {
             self.check_failsafe()?;
 
+            // Virtual-key codes for 0-9 and A-Z match their uppercase ASCII values
             let _key_code = match key {
-                '5' => 0x35, // VK_5
-                '6' => 0x36, // VK_6
+                c if c.is_ascii_alphanumeric() => c.to_ascii_uppercase() as u8,
                 _ => return Err(anyhow!("Unsupported key: {}", key)),
             };
 
@@ -604,9 +2605,11 @@ mod input {
             {
                 // Use Windows API for better Roblox compatibility
                 self.send_key_windows(_key_code, false)?; // Key down
-                thread::sleep(Duration::from_millis(50));
+                mark_key_held(key, true);
+                self.clock.sleep(hold);
                 self.send_key_windows(_key_code, true)?; // Key up
-                thread::sleep(Duration::from_millis(50));
+                mark_key_held(key, false);
+                self.clock.sleep(Duration::from_millis(50));
             }
 
             #[cfg(not(windows))]
@@ -614,99 +2617,465 @@ mod input {
                 // Fallback to enigo for non-Windows systems
                 use enigo::{Direction, Key, Keyboard};
                 self.enigo.key(Key::Other(key as u32), Direction::Press)?;
-                thread::sleep(Duration::from_millis(50));
+                mark_key_held(key, true);
+                self.clock.sleep(hold);
                 self.enigo.key(Key::Other(key as u32), Direction::Release)?;
-                thread::sleep(Duration::from_millis(50));
+                mark_key_held(key, false);
+                self.clock.sleep(Duration::from_millis(50));
             }
 
-            self.last_action_time = Instant::now();
+            self.last_action_time = self.clock.now();
             Ok(())
         }
 
-        pub fn reset_rod(&mut self) -> Result<()> {
-            self.press_key('5')?;
-            thread::sleep(Duration::from_millis(200)); // Longer delay for Roblox
-            self.press_key('5')?;
-            thread::sleep(Duration::from_millis(200));
-            Ok(())
-        }
+        fn release_all(&mut self) -> Result<()> {
+            let keys = HELD_KEYS
+                .lock()
+                .map(|mut keys| std::mem::take(&mut *keys))
+                .unwrap_or_default();
+            let mouse_held = MOUSE_HELD.swap(false, Ordering::SeqCst);
+
+            #[cfg(windows)]
+            {
+                for key in keys {
+                    self.send_key_windows(key.to_ascii_uppercase() as u8, true)?;
+                }
+                if mouse_held {
+                    self.send_mouse_up_windows();
+                }
+            }
+
+            #[cfg(not(windows))]
+            {
+                use enigo::{Button, Direction, Key, Keyboard, Mouse};
+                for key in keys {
+                    self.enigo.key(Key::Other(key as u32), Direction::Release)?;
+                }
+                if mouse_held {
+                    self.enigo.button(Button::Left, Direction::Release)?;
+                }
+            }
 
-        pub fn eat_food(&mut self) -> Result<()> {
-            self.click()?;
-            thread::sleep(Duration::from_millis(200)); // Longer delays for Roblox
-            self.press_key('6')?;
-            thread::sleep(Duration::from_millis(200));
-            self.click()?;
-            thread::sleep(Duration::from_millis(200));
-            self.press_key('5')?;
-            thread::sleep(Duration::from_millis(200));
             Ok(())
         }
 
-        pub fn get_last_action_time(&self) -> Instant {
+        fn get_last_action_time(&self) -> Instant {
             self.last_action_time
         }
-    }
-}
 
-// ===== WEBHOOK MODULE =====
-mod webhook {
-    use super::*;
-    use reqwest::Client;
-    use std::collections::VecDeque;
+        fn clock(&self) -> &dyn Clock {
+            self.clock.as_ref()
+        }
+    }
 
-    pub struct WebhookManager {
-        client: Client,
-        message_queue: Arc<Mutex<VecDeque<WebhookMessage>>>,
-        config: Arc<RwLock<config::BotConfig>>,
-        running: Arc<std::sync::atomic::AtomicBool>,
-        last_screenshot_time: Arc<Mutex<Instant>>,
+    /// Dry-run backend: logs each action instead of sending it, so a session can
+    /// be watched end to end without touching the game.
+    pub struct DryRunInput {
+        clock: Arc<dyn Clock>,
+        last_action_time: Instant,
     }
 
-    #[derive(Debug, Clone)]
-    pub enum WebhookMessage {
-        Text(String),
-        Screenshot {
-            message: String,
-            image_data: Vec<u8>,
-        },
+    impl DryRunInput {
+        fn log(&mut self, action: std::fmt::Arguments) {
+            log::info!("[dry run] {}", action);
+            self.last_action_time = self.clock.now();
+        }
     }
 
-    impl WebhookManager {
-        pub fn new(config: Arc<RwLock<config::BotConfig>>) -> Self {
-            Self {
-                client: Client::new(),
-                message_queue: Arc::new(Mutex::new(VecDeque::new())),
-                config,
-                running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
-                last_screenshot_time: Arc::new(Mutex::new(Instant::now())),
-            }
+    impl InputBackend for DryRunInput {
+        fn click(&mut self) -> Result<()> {
+            self.log(format_args!("click"));
+            Ok(())
         }
 
-        pub fn start(&self) {
-            self.running
-                .store(true, std::sync::atomic::Ordering::Relaxed);
-            let queue = self.message_queue.clone();
-            let config = self.config.clone();
-            let client = self.client.clone();
-            let running = self.running.clone();
-            let last_screenshot = self.last_screenshot_time.clone();
+        fn click_at(&mut self, x: i32, y: i32) -> Result<()> {
+            self.log(format_args!("click at ({}, {})", x, y));
+            Ok(())
+        }
 
-            thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                rt.block_on(async {
-                    Self::webhook_worker(queue, config, client, running, last_screenshot).await;
-                });
-            });
+        fn hold_key(&mut self, key: char, hold: Duration) -> Result<()> {
+            self.log(format_args!("key '{}' for {}ms", key, hold.as_millis()));
+            Ok(())
         }
 
-        pub fn stop(&self) {
-            self.running
-                .store(false, std::sync::atomic::Ordering::Relaxed);
+        fn release_all(&mut self) -> Result<()> {
+            self.log(format_args!("release all"));
+            Ok(())
         }
 
-        pub fn send_message(&self, message: String) {
-            if let Ok(mut queue) = self.message_queue.lock() {
+        fn get_last_action_time(&self) -> Instant {
+            self.last_action_time
+        }
+
+        fn clock(&self) -> &dyn Clock {
+            self.clock.as_ref()
+        }
+    }
+
+    /// One primitive action taken through [`RecordingInput`].
+    #[cfg(test)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum InputAction {
+        Click,
+        ClickAt(i32, i32),
+        Key(char, Duration),
+        ReleaseAll,
+    }
+
+    /// Test backend: keeps every action so a test can assert the exact sequence.
+    #[cfg(test)]
+    pub struct RecordingInput {
+        actions: Arc<Mutex<Vec<InputAction>>>,
+        clock: Arc<dyn Clock>,
+        last_action_time: Instant,
+    }
+
+    #[cfg(test)]
+    impl RecordingInput {
+        pub fn new(clock: Arc<dyn Clock>) -> Self {
+            Self {
+                actions: Arc::new(Mutex::new(Vec::new())),
+                last_action_time: clock.now(),
+                clock,
+            }
+        }
+
+        /// Handle to the recorded actions that stays readable once the backend is boxed.
+        pub fn actions(&self) -> Arc<Mutex<Vec<InputAction>>> {
+            self.actions.clone()
+        }
+
+        fn record(&mut self, action: InputAction) {
+            if let Ok(mut actions) = self.actions.lock() {
+                actions.push(action);
+            }
+            self.last_action_time = self.clock.now();
+        }
+    }
+
+    #[cfg(test)]
+    impl InputBackend for RecordingInput {
+        fn click(&mut self) -> Result<()> {
+            self.record(InputAction::Click);
+            Ok(())
+        }
+
+        fn click_at(&mut self, x: i32, y: i32) -> Result<()> {
+            self.record(InputAction::ClickAt(x, y));
+            Ok(())
+        }
+
+        fn hold_key(&mut self, key: char, hold: Duration) -> Result<()> {
+            self.record(InputAction::Key(key, hold));
+            Ok(())
+        }
+
+        fn release_all(&mut self) -> Result<()> {
+            self.record(InputAction::ReleaseAll);
+            Ok(())
+        }
+
+        fn get_last_action_time(&self) -> Instant {
+            self.last_action_time
+        }
+
+        fn clock(&self) -> &dyn Clock {
+            self.clock.as_ref()
+        }
+    }
+
+    /// Stand-in when the OS refused an input connection (e.g. missing X11 or
+    /// accessibility permission). Every action fails with the original error.
+    pub struct UnavailableInput {
+        error: String,
+        clock: Arc<dyn Clock>,
+        last_action_time: Instant,
+    }
+
+    impl UnavailableInput {
+        fn fail(&self) -> Result<()> {
+            Err(anyhow!("Input unavailable: {}", self.error))
+        }
+    }
+
+    impl InputBackend for UnavailableInput {
+        fn click(&mut self) -> Result<()> {
+            self.fail()
+        }
+
+        fn click_at(&mut self, _x: i32, _y: i32) -> Result<()> {
+            self.fail()
+        }
+
+        fn hold_key(&mut self, _key: char, _hold: Duration) -> Result<()> {
+            self.fail()
+        }
+
+        fn release_all(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_last_action_time(&self) -> Instant {
+            self.last_action_time
+        }
+
+        fn clock(&self) -> &dyn Clock {
+            self.clock.as_ref()
+        }
+
+        fn init_error(&self) -> Option<&str> {
+            Some(&self.error)
+        }
+    }
+}
+
+// ===== WEBHOOK MODULE =====
+mod webhook {
+    use super::*;
+    use reqwest::Client;
+    use std::collections::VecDeque;
+    use std::fs;
+
+    pub struct WebhookManager {
+        client: Client,
+        message_queue: Arc<Mutex<VecDeque<WebhookMessage>>>,
+        config: Arc<RwLock<config::BotConfig>>,
+        running: Arc<std::sync::atomic::AtomicBool>,
+        last_screenshot_time: Arc<Mutex<Instant>>,
+        last_outcome: Arc<Mutex<Option<SendOutcome>>>,
+        /// While in the future, the worker keeps sending after `running` clears.
+        flush_until: Arc<Mutex<Option<Instant>>>,
+        worker_active: Arc<std::sync::atomic::AtomicBool>,
+        /// Per kind: when one was last let through, and how many were held back since.
+        alert_cooldowns: Arc<Mutex<HashMap<AlertKind, (Instant, u32)>>>,
+    }
+
+    /// Messages that can arrive in bursts and are rate-limited by
+    /// [`WebhookManager::try_alert`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum AlertKind {
+        Error,
+        Milestone,
+    }
+
+    /// Result of the most recent webhook request.
+    #[derive(Debug, Clone)]
+    pub enum SendOutcome {
+        Delivered(Instant),
+        /// HTTP status line or transport error.
+        Failed(String),
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub enum WebhookMessage {
+        Text(String),
+        Screenshot {
+            message: String,
+            image_data: Vec<u8>,
+            format: config::ScreenshotFormat,
+        },
+        Embed {
+            title: String,
+            description: String,
+            fields: Vec<(String, String)>,
+        },
+    }
+
+    impl WebhookManager {
+        pub fn new(config: Arc<RwLock<config::BotConfig>>) -> Self {
+            let pending = if config.read().webhook_persist_queue {
+                Self::load_pending()
+            } else {
+                VecDeque::new()
+            };
+
+            Self {
+                client: Client::new(),
+                message_queue: Arc::new(Mutex::new(pending)),
+                config,
+                running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                last_screenshot_time: Arc::new(Mutex::new(Instant::now())),
+                last_outcome: Arc::new(Mutex::new(None)),
+                flush_until: Arc::new(Mutex::new(None)),
+                worker_active: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                alert_cooldowns: Arc::new(Mutex::new(HashMap::new())),
+            }
+        }
+
+        /// Returns `message` if an alert of this kind may go out now, noting how many
+        /// were held back since the last one. Returns `None` inside the cooldown.
+        pub fn try_alert(&self, kind: AlertKind, message: String) -> Option<String> {
+            let cooldown = {
+                let config = self.config.read();
+                Duration::from_secs(match kind {
+                    AlertKind::Error => config.error_alert_cooldown_secs,
+                    AlertKind::Milestone => config.milestone_cooldown_secs,
+                })
+            };
+            let Ok(mut cooldowns) = self.alert_cooldowns.lock() else {
+                return Some(message);
+            };
+
+            let now = Instant::now();
+            let suppressed = match cooldowns.get_mut(&kind) {
+                Some((last_sent, suppressed)) if now.duration_since(*last_sent) < cooldown => {
+                    *suppressed += 1;
+                    return None;
+                }
+                Some((_, suppressed)) => *suppressed,
+                None => 0,
+            };
+            cooldowns.insert(kind, (now, 0));
+
+            Some(if suppressed > 0 {
+                format!("{}\n({} similar suppressed)", message, suppressed)
+            } else {
+                message
+            })
+        }
+
+        fn queue_path() -> PathBuf {
+            directories::ProjectDirs::from("com", "arcane", "fishing-bot")
+                .map(|dirs| dirs.data_dir().join("webhook_queue.json"))
+                .unwrap_or_else(|| PathBuf::from("webhook_queue.json"))
+        }
+
+        /// Takes back messages left unsent by the previous run. The file is removed
+        /// so nothing is delivered twice.
+        fn load_pending() -> VecDeque<WebhookMessage> {
+            let path = Self::queue_path();
+            let pending = fs::read_to_string(&path)
+                .ok()
+                .and_then(|contents| serde_json::from_str(&contents).ok())
+                .unwrap_or_default();
+            fs::remove_file(&path).ok();
+            pending
+        }
+
+        /// Writes unsent text and embed messages to disk for the next run.
+        /// Screenshots are dropped to keep the file small.
+        pub fn save_pending(&self) -> Result<()> {
+            if !self.config.read().webhook_persist_queue {
+                return Ok(());
+            }
+
+            let pending: Vec<WebhookMessage> = match self.message_queue.lock() {
+                Ok(queue) => queue
+                    .iter()
+                    .filter(|message| !matches!(message, WebhookMessage::Screenshot { .. }))
+                    .cloned()
+                    .collect(),
+                Err(_) => return Ok(()),
+            };
+
+            let path = Self::queue_path();
+            if pending.is_empty() {
+                fs::remove_file(&path).ok();
+                return Ok(());
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, serde_json::to_string(&pending)?)?;
+            Ok(())
+        }
+
+        pub fn start(&self) {
+            self.running
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+            self.spawn_worker();
+        }
+
+        fn spawn_worker(&self) {
+            let queue = self.message_queue.clone();
+            let config = self.config.clone();
+            let client = self.client.clone();
+            let running = self.running.clone();
+            let last_outcome = self.last_outcome.clone();
+            let flush_until = self.flush_until.clone();
+            let active = self.worker_active.clone();
+            active.store(true, std::sync::atomic::Ordering::Relaxed);
+
+            thread::spawn(move || {
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                rt.block_on(async {
+                    Self::webhook_worker(queue, config, client, running, last_outcome, flush_until)
+                        .await;
+                });
+                active.store(false, std::sync::atomic::Ordering::Relaxed);
+            });
+        }
+
+        /// Stops the worker but first lets it send what is still queued, blocking
+        /// for at most `timeout`. Used on exit so the session summary goes out.
+        pub fn flush_and_stop(&self, timeout: Duration) {
+            let deadline = Instant::now() + timeout;
+            if let Ok(mut flush_until) = self.flush_until.lock() {
+                *flush_until = Some(deadline);
+            }
+            self.stop();
+
+            let pending = self
+                .message_queue
+                .lock()
+                .map(|queue| !queue.is_empty())
+                .unwrap_or(false);
+            if pending
+                && !self
+                    .worker_active
+                    .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                self.spawn_worker();
+            }
+
+            while self
+                .worker_active
+                .load(std::sync::atomic::Ordering::Relaxed)
+                && Instant::now() < deadline
+            {
+                thread::sleep(Duration::from_millis(50));
+            }
+        }
+
+        pub fn last_outcome(&self) -> Option<SendOutcome> {
+            self.last_outcome
+                .lock()
+                .ok()
+                .and_then(|outcome| outcome.clone())
+        }
+
+        fn record_outcome(
+            last_outcome: &Mutex<Option<SendOutcome>>,
+            result: reqwest::Result<reqwest::Response>,
+        ) {
+            let outcome = match result {
+                Ok(response) if response.status().is_success() => {
+                    SendOutcome::Delivered(Instant::now())
+                }
+                Ok(response) => SendOutcome::Failed(response.status().to_string()),
+                Err(e) => SendOutcome::Failed(e.to_string()),
+            };
+            if let Ok(mut last) = last_outcome.lock() {
+                *last = Some(outcome);
+            }
+        }
+
+        pub fn stop(&self) {
+            self.running
+                .store(false, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        fn present(&self, message: String) -> String {
+            if self.config.read().plain_text_mode {
+                text::plain_text(&message)
+            } else {
+                message
+            }
+        }
+
+        pub fn send_message(&self, message: String) {
+            let message = self.present(message);
+            if let Ok(mut queue) = self.message_queue.lock() {
                 queue.push_back(WebhookMessage::Text(message));
 
                 // Limit queue size
@@ -717,10 +3086,13 @@ mod webhook {
         }
 
         pub fn send_screenshot(&self, message: String, image_data: Vec<u8>) {
+            let message = self.present(message);
+            let format = self.config.read().screenshot_format;
             if let Ok(mut queue) = self.message_queue.lock() {
                 queue.push_back(WebhookMessage::Screenshot {
                     message,
                     image_data,
+                    format,
                 });
 
                 // Limit queue size
@@ -730,6 +3102,32 @@ mod webhook {
             }
         }
 
+        pub fn send_embed(
+            &self,
+            title: String,
+            description: String,
+            fields: Vec<(String, String)>,
+        ) {
+            let title = self.present(title);
+            let description = self.present(description);
+            let fields = fields
+                .into_iter()
+                .map(|(name, value)| (self.present(name), self.present(value)))
+                .collect();
+            if let Ok(mut queue) = self.message_queue.lock() {
+                queue.push_back(WebhookMessage::Embed {
+                    title,
+                    description,
+                    fields,
+                });
+
+                // Limit queue size
+                while queue.len() > 50 {
+                    queue.pop_front();
+                }
+            }
+        }
+
         pub fn check_periodic_screenshot(&self, detector: &detection::AdvancedDetector) {
             let config = self.config.read();
             if !config.screenshot_enabled || config.webhook_url.is_empty() {
@@ -748,18 +3146,71 @@ mod webhook {
                 }
             };
 
+            drop(config);
+
             if should_take {
-                if let Ok(screenshot) = detector.take_full_screenshot() {
-                    let mut image_data = Vec::new();
+                self.send_screen_capture("📸 Periodic Screenshot".to_string(), detector);
+            }
+        }
+
+        /// Captures the full screen, shrinks and encodes it per the screenshot
+        /// settings, and queues it with `message`. Returns whether it was queued.
+        pub fn send_screen_capture(
+            &self,
+            message: String,
+            detector: &detection::AdvancedDetector,
+        ) -> bool {
+            let max_dimension = self.config.read().screenshot_max_dimension;
+            let Ok(screenshot) = detector.take_full_screenshot() else {
+                return false;
+            };
+            let screenshot = Self::downscale_to_max(screenshot, max_dimension);
+            match self.encode_screenshot(screenshot) {
+                Ok(image_data) => {
+                    self.send_screenshot(message, image_data);
+                    true
+                }
+                Err(_) => false,
+            }
+        }
+
+        /// Shrinks the image so its long edge is at most `max_dimension` (0 disables).
+        fn downscale_to_max(image: image::RgbaImage, max_dimension: u32) -> image::RgbaImage {
+            let long_edge = image.width().max(image.height());
+            if max_dimension == 0 || long_edge <= max_dimension {
+                return image;
+            }
+
+            let scale = max_dimension as f32 / long_edge as f32;
+            let width = ((image.width() as f32 * scale).round() as u32).max(1);
+            let height = ((image.height() as f32 * scale).round() as u32).max(1);
+            image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle)
+        }
+
+        /// Encodes a capture using the configured screenshot format and JPEG quality.
+        pub fn encode_screenshot(&self, screenshot: image::RgbaImage) -> Result<Vec<u8>> {
+            let (format, quality) = {
+                let config = self.config.read();
+                (config.screenshot_format, config.jpeg_quality)
+            };
+
+            let image = image::DynamicImage::ImageRgba8(screenshot);
+            let mut image_data = Vec::new();
+            match format {
+                config::ScreenshotFormat::Jpeg => {
+                    let rgb = image.to_rgb8();
+                    image::codecs::jpeg::JpegEncoder::new_with_quality(
+                        &mut image_data,
+                        quality.clamp(1, 100),
+                    )
+                    .encode_image(&rgb)?;
+                }
+                config::ScreenshotFormat::Png => {
                     let mut cursor = std::io::Cursor::new(&mut image_data);
-                    if image::DynamicImage::ImageRgba8(screenshot)
-                        .write_to(&mut cursor, image::ImageFormat::Jpeg)
-                        .is_ok()
-                    {
-                        self.send_screenshot("📸 Periodic Screenshot".to_string(), image_data);
-                    }
+                    image.write_to(&mut cursor, image::ImageFormat::Png)?;
                 }
             }
+            Ok(image_data)
         }
 
         async fn webhook_worker(
@@ -767,15 +3218,36 @@ mod webhook {
             config: Arc<RwLock<config::BotConfig>>,
             client: Client,
             running: Arc<std::sync::atomic::AtomicBool>,
-            _last_screenshot: Arc<Mutex<Instant>>,
+            last_outcome: Arc<Mutex<Option<SendOutcome>>>,
+            flush_until: Arc<Mutex<Option<Instant>>>,
         ) {
-            while running.load(std::sync::atomic::Ordering::Relaxed) {
-                let webhook_url = {
+            loop {
+                let keep_running = running.load(std::sync::atomic::Ordering::Relaxed);
+                let flushing = !keep_running
+                    && flush_until
+                        .lock()
+                        .ok()
+                        .and_then(|until| *until)
+                        .is_some_and(|until| Instant::now() < until)
+                    && !queue.lock().unwrap().is_empty();
+                if !keep_running && !flushing {
+                    break;
+                }
+
+                let (webhook_url, batch_size, message_delay, idle_poll) = {
                     let cfg = config.read();
-                    cfg.webhook_url.clone()
+                    (
+                        cfg.webhook_url.clone(),
+                        cfg.webhook_batch_size.max(1),
+                        cfg.webhook_message_delay_ms,
+                        cfg.webhook_idle_poll_ms,
+                    )
                 };
 
                 if webhook_url.is_empty() {
+                    if flushing {
+                        break;
+                    }
                     tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
                     continue;
                 }
@@ -783,8 +3255,7 @@ mod webhook {
                 let messages = {
                     let mut q = queue.lock().unwrap();
                     let mut batch = Vec::new();
-                    for _ in 0..5 {
-                        // Process up to 5 messages at once
+                    for _ in 0..batch_size {
                         if let Some(msg) = q.pop_front() {
                             batch.push(msg);
                         } else {
@@ -795,117 +3266,329 @@ mod webhook {
                 };
 
                 for message in messages {
-                    match message {
+                    let result = match message {
                         WebhookMessage::Text(text) => {
                             let payload = serde_json::json!({ "content": text });
-                            let _ = client.post(&webhook_url).json(&payload).send().await;
+                            client.post(&webhook_url).json(&payload).send().await
                         }
                         WebhookMessage::Screenshot {
                             message,
                             image_data,
+                            format,
                         } => {
                             let form = reqwest::multipart::Form::new()
                                 .text("content", message)
                                 .part(
                                     "file",
                                     reqwest::multipart::Part::bytes(image_data)
-                                        .file_name("screenshot.jpg")
-                                        .mime_str("image/jpeg")
+                                        .file_name(format.file_name())
+                                        .mime_str(format.mime_type())
                                         .unwrap(),
                                 );
 
-                            let _ = client.post(&webhook_url).multipart(form).send().await;
+                            client.post(&webhook_url).multipart(form).send().await
                         }
-                    }
+                        WebhookMessage::Embed {
+                            title,
+                            description,
+                            fields,
+                        } => {
+                            let fields: Vec<_> = fields
+                                .into_iter()
+                                .map(|(name, value)| {
+                                    serde_json::json!({ "name": name, "value": value, "inline": true })
+                                })
+                                .collect();
+                            let payload = serde_json::json!({
+                                "embeds": [{
+                                    "title": title,
+                                    "description": description,
+                                    "color": 0x4682C8,
+                                    "fields": fields,
+                                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                                }]
+                            });
+                            client.post(&webhook_url).json(&payload).send().await
+                        }
+                    };
+                    Self::record_outcome(&last_outcome, result);
 
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+                    tokio::time::sleep(tokio::time::Duration::from_millis(message_delay)).await;
                 }
 
-                tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+                if !flushing {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(idle_poll)).await;
+                }
             }
         }
     }
 }
 
+// ===== SOUND MODULE =====
+mod sound {
+    use super::*;
+    use rodio::{Decoder, OutputStream, Sink};
+    use std::io::Cursor;
+
+    static CATCH_CHIME: &[u8] = include_bytes!("sounds/catch.wav");
+    static ALERT_TONE: &[u8] = include_bytes!("sounds/alert.wav");
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum Cue {
+        /// Short rising chime for a landed fish.
+        Catch,
+        /// Low buzz for a stop the user didn't ask for.
+        Alert,
+    }
+
+    /// Plays `cue` on its own thread so the bot loop never waits on audio.
+    pub fn play(cue: Cue, volume: f32) {
+        let data = match cue {
+            Cue::Catch => CATCH_CHIME,
+            Cue::Alert => ALERT_TONE,
+        };
+        thread::spawn(move || {
+            if let Err(e) = play_blocking(data, volume) {
+                log::warn!("Failed to play {:?} sound: {}", cue, e);
+            }
+        });
+    }
+
+    fn play_blocking(data: &'static [u8], volume: f32) -> Result<()> {
+        // The stream must outlive the sink, or playback stops immediately
+        let (_stream, handle) = OutputStream::try_default()?;
+        let sink = Sink::try_new(&handle)?;
+        sink.set_volume(volume.clamp(0.0, 1.0));
+        sink.append(Decoder::new(Cursor::new(data))?);
+        sink.sleep_until_end();
+        Ok(())
+    }
+}
+
 // ===== OCR MODULE =====
 mod ocr {
     use super::*;
     use image::{GrayImage, Luma, RgbaImage};
-    use once_cell::sync::Lazy;
+    use once_cell::sync::OnceCell;
     use rusty_tesseract::{Args, Image as TessImage};
+    use std::path::Path;
 
-    static OCR_ARGS: Lazy<Args> = Lazy::new(|| {
-        let mut config_variables = HashMap::new();
-        config_variables.insert(
-            "tessedit_char_whitelist".to_string(),
-            "0123456789%".to_string(),
-        );
+    /// Outcome of the startup Tesseract check; `Some` says why OCR can't run.
+    static TESSERACT_ERROR: OnceCell<Option<String>> = OnceCell::new();
 
-        Args {
-            lang: "eng".to_string(),
-            dpi: Some(150),
-            psm: Some(8),
-            oem: Some(3),
-            config_variables,
-        }
-    });
+    #[cfg(windows)]
+    const TESSERACT_EXE: &str = "tesseract.exe";
+    #[cfg(not(windows))]
+    const TESSERACT_EXE: &str = "tesseract";
 
-    pub struct EnhancedOCRHandler {
-        cache: HashMap<String, (Option<u32>, Instant)>,
-    }
+    /// Where installers usually put Tesseract, tried when it isn't on PATH.
+    #[cfg(windows)]
+    const TESSERACT_INSTALL_DIRS: &[&str] = &[
+        r"C:\Program Files\Tesseract-OCR",
+        r"C:\Program Files (x86)\Tesseract-OCR",
+    ];
+    // Apps launched from Finder don't get the shell's PATH
+    #[cfg(target_os = "macos")]
+    const TESSERACT_INSTALL_DIRS: &[&str] = &["/opt/homebrew/bin", "/usr/local/bin"];
+    #[cfg(not(any(windows, target_os = "macos")))]
+    const TESSERACT_INSTALL_DIRS: &[&str] = &["/usr/local/bin"];
+
+    /// Makes `tesseract` findable and checks it has English data. Runs once, at
+    /// startup; later calls keep the first result.
+    pub fn init_tesseract(custom_path: &str) {
+        TESSERACT_ERROR.get_or_init(|| {
+            let custom_path = custom_path.trim();
+            if !custom_path.is_empty() {
+                prepend_to_path(Path::new(custom_path));
+            } else if rusty_tesseract::get_tesseract_version().is_err() {
+                if let Some(dir) = TESSERACT_INSTALL_DIRS
+                    .iter()
+                    .map(Path::new)
+                    .find(|dir| dir.join(TESSERACT_EXE).is_file())
+                {
+                    prepend_to_path(dir);
+                }
+            }
+
+            match check_tesseract() {
+                Ok(()) => None,
+                Err(e) => {
+                    log::warn!("OCR disabled: {}", e);
+                    Some(e.to_string())
+                }
+            }
+        });
+    }
+
+    /// Why OCR is unavailable, if the startup check failed.
+    pub fn tesseract_error() -> Option<&'static str> {
+        TESSERACT_ERROR.get().and_then(|error| error.as_deref())
+    }
+
+    fn check_tesseract() -> Result<()> {
+        rusty_tesseract::get_tesseract_version()
+            .map_err(|_| anyhow!("Tesseract was not found on PATH"))?;
+        let langs = rusty_tesseract::get_tesseract_langs()
+            .map_err(|e| anyhow!("Could not list Tesseract languages: {}", e))?;
+        if !langs.iter().any(|lang| lang == "eng") {
+            return Err(anyhow!(
+                "English language data (eng.traineddata) is missing"
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepend_to_path(path: &Path) {
+        let dir = if path.is_file() {
+            path.parent().unwrap_or(path)
+        } else {
+            path
+        };
+        let mut paths = vec![dir.to_path_buf()];
+        if let Some(existing) = std::env::var_os("PATH") {
+            paths.extend(std::env::split_paths(&existing));
+        }
+        if let Ok(joined) = std::env::join_paths(paths) {
+            std::env::set_var("PATH", joined);
+        }
+    }
+
+    /// What to read from a numeric on-screen field.
+    #[derive(Debug, Clone)]
+    pub struct OcrOpts {
+        /// Cache key; results for one region never answer reads of another.
+        pub region_id: String,
+        pub whitelist: String,
+        pub psm: i32,
+        pub dpi: i32,
+        /// Stretch the darkest..brightest range of the capture to 0..255.
+        pub contrast_stretch: bool,
+        /// Values above 1 brighten midtones, below 1 darken them.
+        pub gamma: f32,
+        /// Flip light-on-dark text to the dark-on-light Tesseract expects.
+        pub invert: bool,
+        /// Readings above this are treated as misreads.
+        pub max_value: u64,
+        /// Read "N/M" as N as a percentage of M, for bars shown as a fraction.
+        pub fraction_as_percent: bool,
+    }
+
+    impl OcrOpts {
+        pub fn hunger(config: &config::BotConfig) -> Self {
+            Self {
+                region_id: "hunger".to_string(),
+                whitelist: config.ocr_whitelist.clone(),
+                psm: config.ocr_psm,
+                dpi: config.ocr_dpi,
+                contrast_stretch: config.ocr_contrast_stretch,
+                gamma: config.ocr_gamma,
+                invert: config.ocr_invert,
+                max_value: 999,
+                fraction_as_percent: true,
+            }
+        }
+
+        pub fn gold(config: &config::BotConfig) -> Self {
+            Self {
+                region_id: "gold".to_string(),
+                whitelist: "0123456789,".to_string(),
+                psm: 7,
+                dpi: config.ocr_dpi,
+                contrast_stretch: config.ocr_contrast_stretch,
+                gamma: config.ocr_gamma,
+                invert: config.ocr_invert,
+                max_value: u64::MAX,
+                fraction_as_percent: false,
+            }
+        }
+
+        fn tesseract_args(&self) -> Args {
+            let mut config_variables = HashMap::new();
+            config_variables.insert(
+                "tessedit_char_whitelist".to_string(),
+                self.whitelist.clone(),
+            );
+
+            Args {
+                lang: "eng".to_string(),
+                dpi: Some(self.dpi.clamp(70, 1200)),
+                psm: Some(self.psm.clamp(0, 13)),
+                oem: Some(3),
+                config_variables,
+            }
+        }
+    }
+
+    type ReadCache = HashMap<String, (Option<u64>, Instant)>;
+
+    pub struct EnhancedOCRHandler {
+        caches: HashMap<String, ReadCache>,
+    }
 
     impl EnhancedOCRHandler {
         pub fn new() -> Result<Self> {
             Ok(Self {
-                cache: HashMap::new(),
+                caches: HashMap::new(),
             })
         }
 
-        pub fn read_hunger(&mut self, image: &RgbaImage) -> Result<Option<u32>> {
+        pub fn read_number(&mut self, image: &RgbaImage, opts: OcrOpts) -> Result<Option<u64>> {
             // Create cache key from image hash
             let cache_key = format!("{:?}", image.pixels().take(10).collect::<Vec<_>>());
 
             // Check cache first
-            if let Some((cached_result, timestamp)) = self.cache.get(&cache_key) {
+            if let Some((cached_result, timestamp)) = self
+                .caches
+                .get(&opts.region_id)
+                .and_then(|cache| cache.get(&cache_key))
+            {
                 if timestamp.elapsed() < Duration::from_secs(2) {
                     return Ok(*cached_result);
                 }
             }
 
-            let result = self.perform_ocr(image)?;
+            let result = self
+                .perform_ocr(image, &opts)?
+                .and_then(|text| self.parse_reading(&text, &opts));
 
-            // Cache the result
-            self.cache.insert(cache_key, (result, Instant::now()));
-
-            // Clean old cache entries
+            // Cache the result and drop old entries
+            let cache = self.caches.entry(opts.region_id).or_default();
+            cache.insert(cache_key, (result, Instant::now()));
             let now = Instant::now();
-            self.cache.retain(|_, (_, timestamp)| {
+            cache.retain(|_, (_, timestamp)| {
                 now.duration_since(*timestamp) < Duration::from_secs(10)
             });
 
             Ok(result)
         }
 
-        fn perform_ocr(&self, image: &RgbaImage) -> Result<Option<u32>> {
+        pub fn clear_cache(&mut self) {
+            self.caches.clear();
+        }
+
+        /// The binarized image handed to Tesseract for `image`.
+        pub fn preprocess(&self, image: &RgbaImage, opts: &OcrOpts) -> GrayImage {
             // Enhanced preprocessing pipeline for more reliable recognition
             let gray = self.to_grayscale_enhanced(image);
-            let denoised = self.noise_reduction(&gray);
-            let binary = self.apply_adaptive_threshold(&denoised);
+            let leveled = self.adjust_levels(&gray, opts);
+            let denoised = self.noise_reduction(&leveled);
+            self.apply_adaptive_threshold(&denoised)
+        }
+
+        fn perform_ocr(&self, image: &RgbaImage, opts: &OcrOpts) -> Result<Option<String>> {
+            let binary = self.preprocess(image, opts);
 
             // Save to temporary file for rusty-tesseract
             let temp_path = std::env::temp_dir().join(format!(
-                "hunger_ocr_{}.png",
+                "{}_ocr_{}.png",
+                opts.region_id,
                 chrono::Utc::now().timestamp_millis()
             ));
             binary.save(&temp_path)?;
 
             // Run OCR once
             let result = if let Ok(image_tess) = TessImage::from_path(&temp_path) {
-                if let Ok(output) = rusty_tesseract::image_to_string(&image_tess, &OCR_ARGS) {
-                    self.parse_hunger_text(&output)
-                } else {
-                    None
-                }
+                rusty_tesseract::image_to_string(&image_tess, &opts.tesseract_args()).ok()
             } else {
                 None
             };
@@ -927,6 +3610,39 @@ mod ocr {
             })
         }
 
+        fn adjust_levels(&self, gray: &GrayImage, opts: &OcrOpts) -> GrayImage {
+            let (low, high) = if opts.contrast_stretch {
+                gray.pixels().fold((255u8, 0u8), |(low, high), p| {
+                    (low.min(p[0]), high.max(p[0]))
+                })
+            } else {
+                (0, 255)
+            };
+            let range = high.saturating_sub(low).max(1) as f32;
+            let gamma = if opts.gamma.is_finite() && opts.gamma > 0.0 {
+                opts.gamma
+            } else {
+                1.0
+            };
+
+            // One lookup table covers stretch, gamma and inversion
+            let lut: Vec<u8> = (0..=255u8)
+                .map(|value| {
+                    let normalized = ((value as f32 - low as f32) / range).clamp(0.0, 1.0);
+                    let level = (normalized.powf(1.0 / gamma) * 255.0).round() as u8;
+                    if opts.invert {
+                        255 - level
+                    } else {
+                        level
+                    }
+                })
+                .collect();
+
+            GrayImage::from_fn(gray.width(), gray.height(), |x, y| {
+                Luma([lut[gray.get_pixel(x, y)[0] as usize]])
+            })
+        }
+
         fn apply_adaptive_threshold(&self, gray: &GrayImage) -> GrayImage {
             let threshold = self.calculate_otsu_threshold(gray);
 
@@ -1010,32 +3726,186 @@ mod ocr {
             })
         }
 
-        fn parse_hunger_text(&self, text: &str) -> Option<u32> {
-            // Simple parsing - just find the first number
-            let cleaned = text.trim().replace('%', "");
+        /// The value in recognized `text`: a fraction as a percentage when `opts`
+        /// allows it, otherwise the first plausible number.
+        fn parse_reading(&self, text: &str, opts: &OcrOpts) -> Option<u64> {
+            opts.fraction_as_percent
+                .then(|| Self::parse_fraction(text))
+                .flatten()
+                .or_else(|| self.parse_number(text, opts.max_value))
+        }
 
-            // Try direct parsing
-            if let Ok(value) = cleaned.parse::<u32>() {
-                if value <= 999 {
-                    // Reasonable upper limit
-                    return Some(value);
-                }
+        /// "85/100" as 85, "170/200" as 85. `None` unless both sides are numbers
+        /// and the first is no larger than the second.
+        fn parse_fraction(text: &str) -> Option<u64> {
+            let (before, after) = text.split_once('/')?;
+            let before = before.trim_end();
+            let after = after.trim_start();
+            let numerator_start = before
+                .rfind(|c: char| !c.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            let denominator_end = after
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(after.len());
+            let numerator: u64 = before[numerator_start..].parse().ok()?;
+            let denominator: u64 = after[..denominator_end].parse().ok()?;
+            if denominator == 0 || numerator > denominator {
+                return None;
             }
+            let scaled = numerator.checked_mul(100)?.checked_add(denominator / 2)?;
+            Some(scaled / denominator)
+        }
 
-            // Find any numbers in the text
-            let numbers: Vec<u32> = cleaned
-                .split_whitespace()
-                .filter_map(|s| {
-                    s.chars()
-                        .filter(|c| c.is_ascii_digit())
-                        .collect::<String>()
-                        .parse()
-                        .ok()
-                })
-                .filter(|&n| n <= 999)
-                .collect();
+        fn parse_number(&self, text: &str, max_value: u64) -> Option<u64> {
+            // Digit runs, allowing thousands separators inside a run ("12,345")
+            text.split(|c: char| !(c.is_ascii_digit() || c == ','))
+                .map(|run| run.replace(',', ""))
+                .filter_map(|digits| digits.parse::<u64>().ok())
+                .find(|&value| value <= max_value)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn read_hunger(text: &str) -> Option<u64> {
+            let opts = OcrOpts::hunger(&config::BotConfig::default());
+            EnhancedOCRHandler::new()
+                .unwrap()
+                .parse_reading(text, &opts)
+        }
+
+        #[test]
+        fn reads_percent_plain_and_fraction_hunger() {
+            assert_eq!(read_hunger("85%"), Some(85));
+            assert_eq!(read_hunger("85"), Some(85));
+            assert_eq!(read_hunger("85/100"), Some(85));
+            assert_eq!(read_hunger("17 / 20"), Some(85));
+        }
+
+        #[test]
+        fn garbled_hunger_reads_nothing() {
+            assert_eq!(read_hunger(""), None);
+            assert_eq!(read_hunger("%/"), None);
+            assert_eq!(read_hunger("--/--"), None);
+            assert_eq!(read_hunger("abc"), None);
+        }
+
+        #[test]
+        fn fraction_rejects_impossible_and_overflowing_values() {
+            assert_eq!(EnhancedOCRHandler::parse_fraction("5/0"), None);
+            assert_eq!(EnhancedOCRHandler::parse_fraction("120/100"), None);
+            let huge = u64::MAX / 10;
+            assert_eq!(
+                EnhancedOCRHandler::parse_fraction(&format!("{}/{}", huge, huge)),
+                None
+            );
+        }
+    }
+}
+
+// ===== CLOCK MODULE =====
+mod clock {
+    use super::*;
+    use rand::distributions::uniform::{SampleRange, SampleUniform};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Source of time for the bot loop, so timing logic can be driven without real sleeps.
+    pub trait Clock: Send + Sync {
+        fn now(&self) -> Instant;
+        fn sleep(&self, duration: Duration);
+
+        fn since(&self, earlier: Instant) -> Duration {
+            self.now().saturating_duration_since(earlier)
+        }
+    }
+
+    pub struct SystemClock;
+
+    impl Clock for SystemClock {
+        fn now(&self) -> Instant {
+            Instant::now()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            thread::sleep(duration);
+        }
+    }
+
+    /// Test clock: sleeping advances virtual time at once and records the pause.
+    #[cfg(test)]
+    pub struct VirtualClock {
+        now: Mutex<Instant>,
+        sleeps: Mutex<Vec<Duration>>,
+    }
+
+    #[cfg(test)]
+    impl Default for VirtualClock {
+        fn default() -> Self {
+            Self {
+                now: Mutex::new(Instant::now()),
+                sleeps: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    impl VirtualClock {
+        pub fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+
+        /// Every pause taken so far, in order.
+        pub fn sleeps(&self) -> Vec<Duration> {
+            self.sleeps.lock().unwrap().clone()
+        }
+    }
+
+    #[cfg(test)]
+    impl Clock for VirtualClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.advance(duration);
+            self.sleeps.lock().unwrap().push(duration);
+        }
+    }
+
+    /// Shared random source; a fixed seed replays the same jitter sequence every session.
+    pub struct BotRng {
+        rng: Mutex<StdRng>,
+    }
+
+    impl BotRng {
+        pub fn new(seed: u64) -> Self {
+            Self {
+                rng: Mutex::new(Self::make(seed)),
+            }
+        }
+
+        /// Seed 0 draws fresh entropy.
+        fn make(seed: u64) -> StdRng {
+            if seed == 0 {
+                StdRng::from_entropy()
+            } else {
+                StdRng::seed_from_u64(seed)
+            }
+        }
+
+        pub fn reseed(&self, seed: u64) {
+            *self.rng.lock().unwrap() = Self::make(seed);
+        }
 
-            numbers.first().copied()
+        pub fn gen_range<T, R>(&self, range: R) -> T
+        where
+            T: SampleUniform,
+            R: SampleRange<T>,
+        {
+            self.rng.lock().unwrap().gen_range(range)
         }
     }
 }
@@ -1043,11 +3913,19 @@ mod ocr {
 // ===== BOT MODULE =====
 mod bot {
     use super::*;
-    use config::{BotConfig, LifetimeStats};
-    use detection::{AdvancedDetector, Color};
-    use input::RobloxInputController;
-    use ocr::EnhancedOCRHandler;
-    use webhook::WebhookManager;
+    use clock::{BotRng, Clock, SystemClock};
+    use config::{
+        BiteDetectionMode, BotConfig, CatchLog, LifetimeStats, MatchLog, PopupDismiss, Region,
+        SessionCheckpoint, SessionRecord,
+    };
+    use detection::{AdvancedDetector, Color, FrameSource};
+    use i18n::{t, tf};
+    use image::RgbaImage;
+    use input::InputBackend;
+    use ocr::{EnhancedOCRHandler, OcrOpts};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc;
+    use webhook::{AlertKind, WebhookManager};
 
     #[derive(Debug, Clone)]
     pub struct BotState {
@@ -1059,13 +3937,105 @@ mod bot {
         pub status: String,
         pub current_phase: FishingPhase,
         pub errors_count: u32,
-        pub uptime_percentage: f32,
+        /// `None` until `MIN_UPTIME_DATA` of the session has passed.
+        pub uptime_percentage: Option<f32>,
         pub fish_per_hour: f32,
         pub session_best_streak: u32,
         pub current_streak: u32,
+        pub session_feeds: u64,
+        pub next_anti_afk_at: Option<Instant>,
+        /// Session catch count that triggers the next hunger check.
+        pub next_feed_at_fish: Option<u64>,
+        pub auto_recoveries: u32,
+        pub gold_start: Option<u64>,
+        pub gold_latest: Option<u64>,
+        pub session_gold_delta: Option<i64>,
+        pub last_gold_check: Option<Instant>,
+        pub last_error_screenshot: Option<Instant>,
+        pub bite_match_pixels: u32,
+        pub catch_match_pixels: u32,
+        pub current_spot: usize,
+        pub spot_started_at: Option<Instant>,
+        pub spot_fish: u64,
+        pub recent_catches: std::collections::VecDeque<Instant>,
+        /// Wall-clock time of the last `MAX_CATCH_LOG` catches, across sessions.
+        pub catch_log: std::collections::VecDeque<chrono::DateTime<Local>>,
+        /// Matched pixels at each of the last `MAX_MATCH_HISTORY` bites, across sessions.
+        pub bite_match_history: std::collections::VecDeque<u32>,
+        /// Matched pixels at each of the last `MAX_MATCH_HISTORY` catches, across sessions.
+        pub catch_match_history: std::collections::VecDeque<u32>,
+        /// Hunger reads in a row that produced no number.
+        pub hunger_ocr_failures: u32,
+        pub last_safety_feed: Option<Instant>,
+        /// Latest error messages with their local time, newest last.
+        pub recent_errors: std::collections::VecDeque<String>,
+        /// When and at which catch count the session was last checkpointed.
+        pub last_checkpoint: Option<(Instant, u64)>,
+        /// Catch rate over the last few minutes; `None` until there is enough data.
+        pub smoothed_fish_per_hour: Option<f32>,
+        pub consecutive_reel_timeouts: u32,
+        /// Session time spent in each finished phase stretch.
+        pub phase_durations: HashMap<FishingPhase, Duration>,
+        /// When `current_phase` was entered; `None` while stopped.
+        pub phase_started_at: Option<Instant>,
+    }
+
+    impl BotState {
+        /// Switches to `phase`, adding the time spent in the previous one to its total.
+        fn enter_phase(&mut self, phase: FishingPhase, now: Instant) {
+            if let Some(started) = self.phase_started_at.replace(now) {
+                *self
+                    .phase_durations
+                    .entry(self.current_phase.clone())
+                    .or_default() += now.saturating_duration_since(started);
+            }
+            self.current_phase = phase;
+        }
+
+        /// Time per phase this session, including the phase in progress, longest first.
+        pub fn phase_breakdown(&self, now: Instant) -> Vec<(FishingPhase, Duration)> {
+            let mut totals = self.phase_durations.clone();
+            if let Some(started) = self.phase_started_at {
+                *totals.entry(self.current_phase.clone()).or_default() +=
+                    now.saturating_duration_since(started);
+            }
+
+            let mut breakdown: Vec<_> = totals.into_iter().collect();
+            breakdown.sort_by_key(|(_, time)| std::cmp::Reverse(*time));
+            breakdown
+        }
+    }
+
+    /// Errors the run loop handles differently from ordinary failures.
+    #[derive(Debug, thiserror::Error)]
+    pub enum BotError {
+        // The corner check only exists on Windows
+        #[cfg_attr(not(windows), allow(dead_code))]
+        #[error("Failsafe triggered: mouse in top-left corner")]
+        FailsafeTriggered,
+    }
+
+    /// Why a session ended, reported in the stop summary.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+    pub enum StopReason {
+        UserRequested,
+        TargetReached,
+        TooManyErrors,
+        Failsafe,
+    }
+
+    impl StopReason {
+        pub fn label(self) -> &'static str {
+            match self {
+                StopReason::UserRequested => t("stop_reason.user_requested"),
+                StopReason::TargetReached => t("stop_reason.target_reached"),
+                StopReason::TooManyErrors => t("stop_reason.too_many_errors"),
+                StopReason::Failsafe => t("stop_reason.failsafe"),
+            }
+        }
     }
 
-    #[derive(Debug, Clone, PartialEq)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
     pub enum FishingPhase {
         Idle,
         Casting,
@@ -1074,6 +4044,7 @@ mod bot {
         Caught,
         Feeding,
         Error,
+        Failsafe,
     }
 
     impl Default for BotState {
@@ -1087,23 +4058,75 @@ mod bot {
                 status: "Ready to start fishing! 🎣".to_string(),
                 current_phase: FishingPhase::Idle,
                 errors_count: 0,
-                uptime_percentage: 100.0,
+                uptime_percentage: None,
                 fish_per_hour: 0.0,
                 session_best_streak: 0,
                 current_streak: 0,
+                session_feeds: 0,
+                next_anti_afk_at: None,
+                next_feed_at_fish: None,
+                auto_recoveries: 0,
+                gold_start: None,
+                gold_latest: None,
+                session_gold_delta: None,
+                last_gold_check: None,
+                last_error_screenshot: None,
+                bite_match_pixels: 0,
+                catch_match_pixels: 0,
+                current_spot: 0,
+                spot_started_at: None,
+                spot_fish: 0,
+                recent_catches: std::collections::VecDeque::new(),
+                catch_log: std::collections::VecDeque::new(),
+                bite_match_history: std::collections::VecDeque::new(),
+                catch_match_history: std::collections::VecDeque::new(),
+                smoothed_fish_per_hour: None,
+                consecutive_reel_timeouts: 0,
+                phase_durations: HashMap::new(),
+                phase_started_at: None,
+                hunger_ocr_failures: 0,
+                last_safety_feed: None,
+                recent_errors: std::collections::VecDeque::new(),
+                last_checkpoint: None,
             }
         }
     }
 
+    /// Session milestones for external consumers; see [`AdvancedFishingBot::subscribe`].
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(tag = "type")]
+    pub enum BotEvent {
+        Cast,
+        BiteDetected,
+        Caught {
+            fish_count: u64,
+        },
+        /// `hunger` is `None` when fed without a hunger reading.
+        Fed {
+            hunger: Option<u32>,
+        },
+        Error {
+            message: String,
+        },
+        Stopped {
+            reason: StopReason,
+        },
+    }
+
     pub struct AdvancedFishingBot {
         config: Arc<RwLock<BotConfig>>,
         state: Arc<RwLock<BotState>>,
         lifetime_stats: Arc<RwLock<LifetimeStats>>,
         detector: Arc<AdvancedDetector>,
-        input: Arc<Mutex<RobloxInputController>>,
+        input: Arc<Mutex<Box<dyn InputBackend>>>,
         webhook: Arc<WebhookManager>,
         ocr: Arc<Mutex<EnhancedOCRHandler>>,
         performance_monitor: Arc<Mutex<PerformanceMonitor>>,
+        clock: Arc<dyn Clock>,
+        rng: Arc<BotRng>,
+        /// Set while a run loop thread exists, including one still winding down after a stop.
+        loop_active: Arc<AtomicBool>,
+        event_subscribers: Arc<Mutex<Vec<mpsc::Sender<BotEvent>>>>,
     }
 
     #[derive(Debug)]
@@ -1113,8 +4136,77 @@ mod bot {
         error_count: u32,
         last_error_time: Option<Instant>,
         operation_times: std::collections::VecDeque<Duration>,
+        /// Whether each recent cast ended in a catch, newest last.
+        recent_casts: std::collections::VecDeque<bool>,
+        scan_times: std::collections::VecDeque<Instant>,
+        scan_target_hz: f32,
+    }
+
+    /// Session progress is checkpointed after this many catches or this much time.
+    const CHECKPOINT_EVERY_FISH: u64 = 5;
+    const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Error messages kept for the diagnostics report.
+    const MAX_RECENT_ERRORS: usize = 10;
+
+    /// How often the daily summary scheduler checks whether the report is due.
+    const DAILY_SUMMARY_POLL: Duration = Duration::from_secs(30);
+
+    /// Session time before an uptime percentage means anything.
+    const MIN_UPTIME_DATA: Duration = Duration::from_secs(30);
+
+    /// Catch times kept in memory for [`AdvancedFishingBot::catch_times`].
+    pub const MAX_CATCH_LOG: usize = 1000;
+
+    /// Match counts kept in memory for [`AdvancedFishingBot::match_history`].
+    const MAX_MATCH_HISTORY: usize = 200;
+
+    /// One line of [`AdvancedFishingBot::self_check`].
+    #[derive(Debug, Clone)]
+    pub struct SelfCheck {
+        pub name: String,
+        /// What went wrong and what to try; `None` when the check passed.
+        pub problem: Option<String>,
+    }
+
+    impl SelfCheck {
+        fn new(name: impl Into<String>, problem: Option<String>) -> Self {
+            Self {
+                name: name.into(),
+                problem,
+            }
+        }
+    }
+
+    /// Which detection a recorded match count came from.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum MatchKind {
+        Bite,
+        Catch,
+    }
+
+    impl MatchKind {
+        fn label(self) -> &'static str {
+            match self {
+                MatchKind::Bite => "bite",
+                MatchKind::Catch => "catch",
+            }
+        }
     }
 
+    /// Failed hunger reads in a row before the bot stops feeding blindly.
+    pub const HUNGER_OCR_FAILURE_LIMIT: u32 = 3;
+
+    /// Shortest gap between timed feeds while Tesseract is missing, whatever the
+    /// safety cooldown says.
+    const MIN_TIMED_FEED_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Minimum gap between screenshots attached to error alerts.
+    const ERROR_SCREENSHOT_COOLDOWN: Duration = Duration::from_secs(60);
+
+    /// Window the effective scan rate is averaged over.
+    const SCAN_RATE_WINDOW: Duration = Duration::from_secs(2);
+
     impl PerformanceMonitor {
         fn new() -> Self {
             Self {
@@ -1123,7 +4215,40 @@ mod bot {
                 error_count: 0,
                 last_error_time: None,
                 operation_times: std::collections::VecDeque::new(),
+                recent_casts: std::collections::VecDeque::new(),
+                scan_times: std::collections::VecDeque::new(),
+                scan_target_hz: 0.0,
+            }
+        }
+
+        fn record_scan(&mut self, now: Instant, target_hz: f32) {
+            self.scan_times.push_back(now);
+            while self
+                .scan_times
+                .front()
+                .is_some_and(|&at| now.duration_since(at) > SCAN_RATE_WINDOW)
+            {
+                self.scan_times.pop_front();
+            }
+            self.scan_target_hz = target_hz;
+        }
+
+        /// Scans per second over the last window with the target it was aiming for,
+        /// or `None` once scanning has stopped.
+        fn get_scan_rate(&self, now: Instant) -> Option<(f32, f32)> {
+            let last = *self.scan_times.back()?;
+            if now.duration_since(last) > Duration::from_secs(1) {
+                return None;
             }
+            let recent = self
+                .scan_times
+                .iter()
+                .filter(|&&at| now.duration_since(at) <= SCAN_RATE_WINDOW)
+                .count();
+            Some((
+                recent as f32 / SCAN_RATE_WINDOW.as_secs_f32(),
+                self.scan_target_hz,
+            ))
         }
 
         fn record_operation(&mut self, duration: Duration, success: bool) {
@@ -1141,6 +4266,34 @@ mod bot {
             }
         }
 
+        fn record_cast(&mut self, caught: bool) {
+            self.recent_casts.push_back(caught);
+            while self.recent_casts.len() > 500 {
+                self.recent_casts.pop_front();
+            }
+        }
+
+        /// Share of the last `window` casts that caught a fish, as a percentage, or
+        /// `None` until that many casts have been recorded.
+        fn get_rolling_catch_rate(&self, window: usize) -> Option<f32> {
+            if window == 0 || self.recent_casts.len() < window {
+                return None;
+            }
+
+            let catches = self
+                .recent_casts
+                .iter()
+                .rev()
+                .take(window)
+                .filter(|&&caught| caught)
+                .count();
+            Some(catches as f32 / window as f32 * 100.0)
+        }
+
+        fn clear_recent_casts(&mut self) {
+            self.recent_casts.clear();
+        }
+
         fn get_success_rate(&self) -> f32 {
             if self.total_operations == 0 {
                 return 100.0;
@@ -1160,12 +4313,62 @@ mod bot {
 
     impl AdvancedFishingBot {
         pub fn new(config: BotConfig, lifetime_stats: LifetimeStats) -> Self {
+            let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+            let input = input::backend_for(&config, clock.clone());
+            let frames = detection::frame_source_for(&config);
+            let bot = Self::with_backends(config.clone(), lifetime_stats, input, frames, clock);
+
+            // Webhooks and the daily summary work between sessions too, so both
+            // live as long as the app
+            bot.webhook.start();
+            let scheduler = bot.clone();
+            thread::spawn(move || loop {
+                scheduler.check_daily_summary();
+                scheduler.clock.sleep(DAILY_SUMMARY_POLL);
+            });
+
+            if config.status_server_enabled {
+                server::spawn(
+                    config.status_server_port,
+                    config.status_server_token.clone(),
+                    bot.clone(),
+                );
+                server::spawn_websocket(config.status_ws_port, bot.clone());
+            }
+
+            bot
+        }
+
+        /// A bot that acts through `input`, sees the screen through `frames` and
+        /// waits on `clock`.
+        fn with_backends(
+            config: BotConfig,
+            lifetime_stats: LifetimeStats,
+            input: Box<dyn InputBackend>,
+            frames: Box<dyn FrameSource>,
+            clock: Arc<dyn Clock>,
+        ) -> Self {
             let config_arc = Arc::new(RwLock::new(config.clone()));
-            let detector = Arc::new(AdvancedDetector::new(
-                config.detection_interval_ms,
-                config.color_tolerance,
-                config.advanced_detection,
-            ));
+            let detector = Arc::new(
+                AdvancedDetector::new(
+                    config.screenshot_cache_ms,
+                    config.color_tolerance,
+                    config.min_match_pixels,
+                    config.min_cluster_pixels,
+                    config.advanced_detection,
+                    frames,
+                )
+                .with_low_light_boost(
+                    config.low_light_tolerance_boost,
+                    config.low_light_brightness,
+                )
+                .with_downsample(config.detection_downsample)
+                .with_match_fraction(
+                    config
+                        .match_fraction_enabled
+                        .then_some(config.min_match_fraction),
+                ),
+            );
             let webhook = Arc::new(WebhookManager::new(config_arc.clone()));
 
             Self {
@@ -1173,42 +4376,113 @@ mod bot {
                 state: Arc::new(RwLock::new(BotState::default())),
                 lifetime_stats: Arc::new(RwLock::new(lifetime_stats)),
                 detector,
-                input: Arc::new(Mutex::new(RobloxInputController::new(
-                    config.failsafe_enabled,
-                ))),
+                input: Arc::new(Mutex::new(input)),
                 webhook,
                 ocr: Arc::new(Mutex::new(
                     EnhancedOCRHandler::new()
                         .unwrap_or_else(|_| EnhancedOCRHandler::new().unwrap()),
                 )),
                 performance_monitor: Arc::new(Mutex::new(PerformanceMonitor::new())),
+                clock,
+                rng: Arc::new(BotRng::new(config.rng_seed)),
+                loop_active: Arc::new(AtomicBool::new(false)),
+                event_subscribers: Arc::new(Mutex::new(Vec::new())),
             }
         }
 
-        pub fn start(&self) {
+        /// Starts a session unless one is running. Returns whether a new run loop
+        /// was started.
+        pub fn start(&self) -> bool {
+            self.start_with(None)
+        }
+
+        /// Starts a session, seeding its counters from `resume` before the run loop
+        /// can see them.
+        fn start_with(&self, resume: Option<&SessionCheckpoint>) -> bool {
+            let now = self.clock.now();
             let mut state = self.state.write();
             if state.running {
-                return;
+                return false;
+            }
+
+            if let Some(error) = self.input_init_error() {
+                state.status = self.present(&tf("status.input_unavailable", &[("error", &error)]));
+                return false;
+            }
+
+            // Claimed for the lifetime of the loop thread, so a quick stop and start
+            // can't leave the previous loop running next to a new one
+            if self
+                .loop_active
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+            {
+                state.status = self.present(t("status.still_stopping"));
+                return false;
             }
 
             state.running = true;
             state.paused = false;
             state.fish_count = 0;
-            state.start_time = Some(Instant::now());
-            state.status = "🚀 Starting advanced fishing bot...".to_string();
+            state.start_time = Some(now);
+            state.status = self.present(t("status.starting"));
             state.current_phase = FishingPhase::Idle;
+            state.phase_durations.clear();
+            state.phase_started_at = Some(now);
             state.errors_count = 0;
             state.current_streak = 0;
+            state.session_best_streak = 0;
+            state.session_feeds = 0;
+            state.next_anti_afk_at = None;
+            state.next_feed_at_fish = None;
+            state.auto_recoveries = 0;
+            state.gold_start = None;
+            state.gold_latest = None;
+            state.session_gold_delta = None;
+            state.last_gold_check = None;
+            state.current_spot = 0;
+            state.spot_started_at = Some(now);
+            state.spot_fish = 0;
+            state.recent_catches.clear();
+            state.smoothed_fish_per_hour = None;
+            state.consecutive_reel_timeouts = 0;
+            state.uptime_percentage = None;
+            state.hunger_ocr_failures = 0;
+            state.last_safety_feed = None;
+            state.last_checkpoint = None;
+            if let Some(checkpoint) = resume {
+                state.fish_count = checkpoint.fish;
+                state.session_feeds = checkpoint.feeds;
+                state.session_best_streak = checkpoint.best_streak;
+                state.current_streak = checkpoint.current_streak;
+                state.errors_count = checkpoint.errors;
+                state.start_time = Some(
+                    now.checked_sub(Duration::from_secs(checkpoint.runtime_seconds))
+                        .unwrap_or(now),
+                );
+            }
             drop(state);
 
-            // Start webhook manager
-            self.webhook.start();
+            if let Some(checkpoint) = resume {
+                self.schedule_feed(checkpoint.fish);
+            }
+
+            // A fixed seed makes every session replay the same random timings
+            self.rng.reseed(self.config.read().rng_seed);
+
+            // The session starts at the first spot of the rotation
+            if self.config.read().spot_rotation_enabled {
+                self.apply_spot(0);
+            }
+            self.refresh_active_regions();
 
             // Send startup notification
-            self.webhook.send_message(
-                "🎣 Advanced Fishing Bot Started! Beginning automated fishing session..."
-                    .to_string(),
-            );
+            if self.config.read().notify_on_start {
+                self.webhook.send_message(
+                    "🎣 Advanced Fishing Bot Started! Beginning automated fishing session..."
+                        .to_string(),
+                );
+            }
 
             // Run bot in separate thread
             let config = self.config.clone();
@@ -1217,6 +4491,11 @@ mod bot {
             let detector = self.detector.clone();
             let webhook = self.webhook.clone();
             let performance_monitor = self.performance_monitor.clone();
+            let clock = self.clock.clone();
+            let rng = self.rng.clone();
+            let loop_active = self.loop_active.clone();
+            let event_subscribers = self.event_subscribers.clone();
+            let input = self.input.clone();
 
             thread::spawn(move || {
                 let bot_clone = Self {
@@ -1224,56 +4503,222 @@ mod bot {
                     state,
                     lifetime_stats,
                     detector,
-                    input: Arc::new(Mutex::new(RobloxInputController::new(
-                        config.read().failsafe_enabled,
-                    ))),
+                    input,
                     webhook,
                     ocr: Arc::new(Mutex::new(
                         EnhancedOCRHandler::new()
                             .unwrap_or_else(|_| EnhancedOCRHandler::new().unwrap()),
                     )),
                     performance_monitor,
+                    clock,
+                    rng,
+                    loop_active: loop_active.clone(),
+                    event_subscribers,
                 };
                 bot_clone.run_loop();
+                loop_active.store(false, Ordering::Release);
             });
+            true
         }
 
-        pub fn stop(&self) {
-            let mut state = self.state.write();
-            state.running = false;
-            state.current_phase = FishingPhase::Idle;
-            state.status = "🛑 Bot stopped".to_string();
-
-            if let Some(start_time) = state.start_time {
-                let runtime = start_time.elapsed().as_secs();
-                let session_fish = state.fish_count;
-                drop(state);
+        /// Scan all regions from one shared capture per frame.
+        fn refresh_active_regions(&self) {
+            let config = self.config.read();
+            let mut regions = vec![
+                config.red_region,
+                config.yellow_region,
+                config.hunger_region,
+            ];
+            if config.rod_check_enabled {
+                regions.push(config.rod_region);
+            }
+            if config.popup_check_enabled {
+                regions.push(config.popup_region);
+            }
+            if config.minigame_start_check_enabled {
+                regions.push(config.minigame_start_region);
+            }
+            if config.loading_check_enabled {
+                regions.push(config.loading_region);
+            }
+            if config.gold_tracking_enabled {
+                regions.push(config.gold_region);
+            }
+            self.detector.set_active_regions(&regions);
+        }
 
-                let mut stats = self.lifetime_stats.write();
-                stats.add_runtime(runtime);
-                stats.complete_session(session_fish);
-                drop(stats);
+        /// Points detection at `index`'s regions; does nothing for an unknown spot.
+        fn apply_spot(&self, index: usize) {
+            let mut config = self.config.write();
+            if let Some(spot) = config.spots.get(index).cloned() {
+                config.red_region = spot.red_region;
+                config.yellow_region = spot.yellow_region;
+            }
+        }
 
-                // Send session summary
-                self.webhook.send_message(format!(
-                    "📊 Session Complete!\n🐟 Fish Caught: {}\n⏱️ Runtime: {}h {}m\n🎯 Best Streak: {}",
-                    session_fish,
-                    runtime / 3600,
-                    (runtime % 3600) / 60,
-                    self.state.read().session_best_streak
-                ));
+        fn check_spot_rotation(&self) {
+            let config = self.config.read();
+            if !config.spot_rotation_enabled || config.spots.len() < 2 {
+                return;
             }
+            let spot_count = config.spots.len();
+            let max_time = Duration::from_secs(config.spot_rotation_minutes as u64 * 60);
+            let max_fish = config.spot_rotation_fish as u64;
+            drop(config);
 
-            self.webhook.stop();
+            let state = self.state.read();
+            let time_up = max_time > Duration::ZERO
+                && state
+                    .spot_started_at
+                    .is_some_and(|started| self.clock.since(started) >= max_time);
+            let fish_up = max_fish > 0 && state.spot_fish >= max_fish;
+            let next = (state.current_spot + 1) % spot_count;
+            drop(state);
+
+            if time_up || fish_up {
+                self.move_to_spot(next);
+            }
         }
 
-        pub fn pause(&self) {
-            let mut state = self.state.write();
-            state.paused = !state.paused;
-            state.status = if state.paused {
-                "⏸️ Bot paused".to_string()
-            } else {
-                "▶️ Bot resumed".to_string()
+        fn move_to_spot(&self, index: usize) {
+            let spot = match self.config.read().spots.get(index) {
+                Some(spot) => spot.clone(),
+                None => return,
+            };
+
+            self.update_phase(FishingPhase::Idle);
+            self.update_status(&tf("status.moving_to_spot", &[("spot", &spot.name)]));
+            if let Ok(mut input) = self.input.lock() {
+                for step in &spot.movement {
+                    if let Err(e) = input.hold_key(step.key, Duration::from_millis(step.hold_ms)) {
+                        log::warn!("Movement step {:?} failed: {}", step, e);
+                    }
+                }
+            }
+
+            self.apply_spot(index);
+            self.refresh_active_regions();
+
+            let mut state = self.state.write();
+            state.current_spot = index;
+            state.spot_started_at = Some(self.clock.now());
+            state.spot_fish = 0;
+        }
+
+        pub fn stop(&self, reason: StopReason) {
+            let mut state = self.state.write();
+            let was_running = state.running;
+            state.running = false;
+            SessionCheckpoint::clear();
+            self.release_input();
+            let now = self.clock.now();
+            // A failsafe stop stays visible in the phase display until the next start
+            let final_phase = if reason == StopReason::Failsafe {
+                FishingPhase::Failsafe
+            } else {
+                FishingPhase::Idle
+            };
+            state.enter_phase(final_phase, now);
+            state.phase_started_at = None;
+            if was_running && matches!(reason, StopReason::TooManyErrors | StopReason::Failsafe) {
+                self.play_sound(sound::Cue::Alert, |config| config.sound_on_error_stop);
+            }
+            if was_running {
+                self.emit(BotEvent::Stopped { reason });
+            }
+            state.status = match reason {
+                StopReason::UserRequested => self.present(t("status.stopped")),
+                StopReason::Failsafe => self.present(t("status.failsafe")),
+                _ => self.present(&tf("status.stopped_reason", &[("reason", &reason.label())])),
+            };
+
+            if let (true, Some(start_time)) = (was_running, state.start_time) {
+                let runtime = self.clock.since(start_time).as_secs();
+                let session_fish = state.fish_count;
+                let record = SessionRecord {
+                    started_at: (Local::now() - chrono::Duration::seconds(runtime as i64))
+                        .to_rfc3339(),
+                    fish: session_fish,
+                    feeds: state.session_feeds,
+                    runtime_seconds: runtime,
+                    best_streak: state.session_best_streak,
+                    errors: state.errors_count,
+                };
+                let gold_delta = state.session_gold_delta;
+                drop(state);
+
+                let mut stats = self.lifetime_stats.write();
+                stats.add_runtime(runtime);
+                stats.complete_session(record);
+                drop(stats);
+
+                // Send session summary
+                if self.config.read().notify_on_stop {
+                    let mut fields = vec![
+                        ("🐟 Fish Caught".to_string(), session_fish.to_string()),
+                        (
+                            "⏱️ Runtime".to_string(),
+                            format!("{}h {}m", runtime / 3600, (runtime % 3600) / 60),
+                        ),
+                        (
+                            "🎯 Best Streak".to_string(),
+                            self.state.read().session_best_streak.to_string(),
+                        ),
+                    ];
+                    if let Some(delta) = gold_delta {
+                        fields.push(("💰 Gold Earned".to_string(), format!("{:+}", delta)));
+                    }
+                    self.webhook.send_embed(
+                        "📊 Session Complete!".to_string(),
+                        format!("Ended: {}", reason.label()),
+                        fields,
+                    );
+                }
+            }
+        }
+
+        /// Returns a receiver for every [`BotEvent`] from now on. Dropping it
+        /// unsubscribes.
+        pub fn subscribe(&self) -> mpsc::Receiver<BotEvent> {
+            let (sender, receiver) = mpsc::channel();
+            if let Ok(mut subscribers) = self.event_subscribers.lock() {
+                subscribers.push(sender);
+            }
+            receiver
+        }
+
+        fn emit(&self, event: BotEvent) {
+            if let Ok(mut subscribers) = self.event_subscribers.lock() {
+                subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+            }
+        }
+
+        pub fn webhook_outcome(&self) -> Option<webhook::SendOutcome> {
+            self.webhook.last_outcome()
+        }
+
+        /// Gives queued webhooks, such as the stop summary, up to `timeout` to send.
+        pub fn flush_webhooks(&self, timeout: Duration) {
+            self.webhook.flush_and_stop(timeout);
+        }
+
+        /// Keeps queued notifications, such as the stop summary, for the next launch.
+        pub fn save_pending_webhooks(&self) {
+            if let Err(e) = self.webhook.save_pending() {
+                log::warn!("Failed to save pending webhooks: {}", e);
+            }
+        }
+
+        pub fn pause(&self) {
+            let mut state = self.state.write();
+            state.paused = !state.paused;
+            if state.paused {
+                self.release_input();
+            }
+            state.status = if state.paused {
+                self.present(t("status.paused"))
+            } else {
+                self.present(t("status.resumed"))
             };
 
             let message = if state.paused {
@@ -1281,13 +4726,70 @@ mod bot {
             } else {
                 "▶️ Bot Resumed"
             };
-            self.webhook.send_message(message.to_string());
+            if self.config.read().notify_on_pause {
+                self.webhook.send_message(message.to_string());
+            }
         }
 
         pub fn get_state(&self) -> BotState {
             self.state.read().clone()
         }
 
+        /// Reads the hunger region once with `config`'s OCR settings and saves the raw
+        /// and preprocessed captures, so unreadable text can be tuned without rebuilding.
+        pub fn test_hunger_ocr(&self, config: &BotConfig) -> Result<(Option<u32>, PathBuf)> {
+            let screenshot = self.detector.get_screenshot(config.hunger_region)?;
+            let opts = OcrOpts::hunger(config);
+            let dir = directories::ProjectDirs::from("com", "arcane", "fishing-bot")
+                .map(|dirs| dirs.data_dir().join("ocr_debug"))
+                .unwrap_or_else(|| PathBuf::from("ocr_debug"));
+            std::fs::create_dir_all(&dir)?;
+
+            let mut ocr = self.ocr.lock().unwrap();
+            screenshot.save(dir.join("hunger_before.png"))?;
+            ocr.preprocess(&screenshot, &opts)
+                .save(dir.join("hunger_after.png"))?;
+
+            ocr.clear_cache();
+            let hunger = ocr
+                .read_number(&screenshot, opts)?
+                .map(|value| value as u32);
+            Ok((hunger, dir))
+        }
+
+        /// Matched pixels at recent bites and catches, oldest first.
+        pub fn match_history(&self) -> (Vec<u32>, Vec<u32>) {
+            let state = self.state.read();
+            (
+                state.bite_match_history.iter().copied().collect(),
+                state.catch_match_history.iter().copied().collect(),
+            )
+        }
+
+        fn record_match_count(&self, kind: MatchKind, pixels: u32) {
+            let mut state = self.state.write();
+            let history = match kind {
+                MatchKind::Bite => &mut state.bite_match_history,
+                MatchKind::Catch => &mut state.catch_match_history,
+            };
+            history.push_back(pixels);
+            if history.len() > MAX_MATCH_HISTORY {
+                history.pop_front();
+            }
+            drop(state);
+
+            if self.config.read().match_log_csv {
+                if let Err(e) = MatchLog::append(Local::now(), kind.label(), pixels) {
+                    log::warn!("Failed to append to match log: {}", e);
+                }
+            }
+        }
+
+        /// Wall-clock times of recent catches, oldest first.
+        pub fn catch_times(&self) -> Vec<chrono::DateTime<Local>> {
+            self.state.read().catch_log.iter().copied().collect()
+        }
+
         pub fn get_lifetime_stats(&self) -> LifetimeStats {
             self.lifetime_stats.read().clone()
         }
@@ -1301,105 +4803,471 @@ mod bot {
             )
         }
 
+        /// Checks each thing fishing depends on, in the order a new user would hit
+        /// them. Fully offline: nothing is clicked and nothing is sent.
+        pub fn self_check(&self) -> Vec<SelfCheck> {
+            let mut checks = Vec::new();
+
+            let frame = self.detector.take_full_screenshot();
+            checks.push(SelfCheck::new(
+                t("selfcheck.capture"),
+                frame.as_ref().err().map(|e| {
+                    tf(
+                        "selfcheck.capture_failed",
+                        &[("error", &e), ("screen", &self.config.read().screen_index)],
+                    )
+                }),
+            ));
+
+            if let Ok(frame) = &frame {
+                let (width, height) = frame.dimensions();
+                for (name, region) in self.named_regions() {
+                    let inside = region.x >= 0
+                        && region.y >= 0
+                        && region.width > 0
+                        && region.height > 0
+                        && region.x as u32 + region.width <= width
+                        && region.y as u32 + region.height <= height;
+                    checks.push(SelfCheck::new(
+                        tf("selfcheck.region", &[("region", &name)]),
+                        (!inside).then(|| {
+                            tf(
+                                "selfcheck.region_outside",
+                                &[("width", &width), ("height", &height)],
+                            )
+                        }),
+                    ));
+                }
+
+                // Same threshold the loop waits on, so the two agree on a blank screen
+                let blank_ratio = self.config.read().blank_frame_ratio / 100.0;
+                checks.push(SelfCheck::new(
+                    t("selfcheck.not_blank"),
+                    AdvancedDetector::is_blank(frame, blank_ratio)
+                        .then(|| t("selfcheck.blank").to_string()),
+                ));
+            }
+
+            checks.push(SelfCheck::new(
+                t("selfcheck.tesseract"),
+                ocr::tesseract_error()
+                    .map(|error| tf("selfcheck.tesseract_missing", &[("error", &error)])),
+            ));
+
+            let input_problem = self
+                .input_init_error()
+                .map(|error| tf("selfcheck.input_failed", &[("error", &error)]))
+                .or_else(|| {
+                    (!self.config.read().input_dry_run && !input::accessibility_trusted())
+                        .then(|| t("selfcheck.input_untrusted").to_string())
+                });
+            checks.push(SelfCheck::new(t("selfcheck.input"), input_problem));
+
+            checks
+        }
+
+        /// Every region the current settings will capture, labelled as in the settings.
+        fn named_regions(&self) -> Vec<(&'static str, Region)> {
+            let config = self.config.read();
+            let mut regions = vec![
+                (t("selfcheck.region_bite"), config.red_region),
+                (t("selfcheck.region_catch"), config.yellow_region),
+                (t("selfcheck.region_hunger"), config.hunger_region),
+            ];
+            let optional = [
+                (
+                    config.rod_check_enabled,
+                    t("selfcheck.region_rod"),
+                    config.rod_region,
+                ),
+                (
+                    config.popup_check_enabled,
+                    t("selfcheck.region_popup"),
+                    config.popup_region,
+                ),
+                (
+                    config.gold_tracking_enabled,
+                    t("selfcheck.region_gold"),
+                    config.gold_region,
+                ),
+                (
+                    config.minigame_start_check_enabled,
+                    t("selfcheck.region_minigame"),
+                    config.minigame_start_region,
+                ),
+                (
+                    config.loading_check_enabled,
+                    t("selfcheck.region_loading"),
+                    config.loading_region,
+                ),
+            ];
+            for (enabled, name, region) in optional {
+                if enabled {
+                    regions.push((name, region));
+                }
+            }
+            regions
+        }
+
+        /// Why input could not be initialized, if it couldn't.
+        pub fn input_init_error(&self) -> Option<String> {
+            self.input
+                .lock()
+                .ok()
+                .and_then(|input| input.init_error().map(str::to_string))
+        }
+
+        /// Runs the successful-catch path as if a fish had landed, for checking stats,
+        /// milestones and feed cadence. Refused unless the bot was launched with both
+        /// `dev_mode` and `input_dry_run`, so it never sends real input.
+        pub fn simulate_catch(&self) -> bool {
+            let config = self.config.read();
+            if !(config.dev_mode && config.input_dry_run) {
+                return false;
+            }
+            drop(config);
+
+            // The catch path sleeps through the post-catch delay
+            let bot = self.clone();
+            thread::spawn(move || bot.handle_successful_catch());
+            true
+        }
+
+        /// Whether this bot was built with the logging-only input backend.
+        pub fn is_dry_run(&self) -> bool {
+            self.config.read().input_dry_run
+        }
+
+        /// Whether the top-left-corner failsafe is watching the mouse. It needs the
+        /// real Windows input backend.
+        pub fn failsafe_armed(&self) -> bool {
+            let config = self.config.read();
+            cfg!(windows) && config.failsafe_enabled && !config.input_dry_run
+        }
+
+        /// Current screen contents of a region, for settings previews.
+        pub fn capture_region(&self, region: Region) -> Result<RgbaImage> {
+            self.detector.get_screenshot(region)
+        }
+
+        /// Effective and target detection rate in Hz while the bot is scanning.
+        pub fn get_scan_rate(&self) -> Option<(f32, f32)> {
+            self.performance_monitor
+                .lock()
+                .ok()?
+                .get_scan_rate(self.clock.now())
+        }
+
+        fn record_scan(&self, target_hz: f32) {
+            if let Ok(mut monitor) = self.performance_monitor.lock() {
+                monitor.record_scan(self.clock.now(), target_hz);
+            }
+        }
+
         pub fn get_last_action_elapsed(&self) -> Option<Duration> {
             self.input
                 .lock()
                 .ok()
-                .map(|controller| controller.get_last_action_time().elapsed())
+                .map(|controller| self.clock.since(controller.get_last_action_time()))
         }
 
         fn run_loop(&self) {
-            self.update_status("🔧 Initializing bot systems...");
+            self.update_status(t("status.initializing"));
             self.update_phase(FishingPhase::Idle);
 
-            thread::sleep(Duration::from_millis(self.config.read().startup_delay_ms));
+            self.clock
+                .sleep(Duration::from_millis(self.config.read().startup_delay_ms));
 
             // Initialize rod state
-            self.update_status("🎣 Preparing fishing rod...");
-            if let Ok(mut input) = self.input.lock() {
-                input.reset_rod().ok();
-            }
+            self.update_status(t("status.preparing_rod"));
+            self.reset_rod();
 
-            // Send startup screenshot
-            if self.config.read().screenshot_enabled {
-                if let Ok(screenshot) = self.detector.take_full_screenshot() {
-                    let mut image_data = Vec::new();
-                    let mut cursor = std::io::Cursor::new(&mut image_data);
-                    if image::DynamicImage::ImageRgba8(screenshot)
-                        .write_to(&mut cursor, image::ImageFormat::Jpeg)
-                        .is_ok()
-                    {
-                        self.webhook.send_screenshot(
-                            "🚀 Bot Started - Ready to Fish!".to_string(),
-                            image_data,
-                        );
+            // Send startup screenshot off the fishing thread so the first cast isn't held up
+            let config = self.config.read();
+            let startup_screenshot = config.screenshot_enabled
+                && config.notify_on_start
+                && config.send_startup_screenshot;
+            drop(config);
+            if startup_screenshot {
+                let webhook = self.webhook.clone();
+                let detector = self.detector.clone();
+                thread::spawn(move || {
+                    if !webhook.send_screen_capture(
+                        "🚀 Bot Started - Ready to Fish!".to_string(),
+                        &detector,
+                    ) {
+                        log::warn!("Startup screenshot could not be captured or encoded");
                     }
-                }
+                });
             }
 
-            self.update_status("🌊 Bot active! Starting fishing sequence...");
+            let first_cast_delay = self.config.read().first_cast_delay_ms;
+            if first_cast_delay > 0 {
+                self.update_status(t("status.waiting_first_cast"));
+                self.clock.sleep(Duration::from_millis(first_cast_delay));
+            }
+
+            self.update_status(t("status.active"));
 
             let mut consecutive_errors = 0;
             let max_consecutive_errors = 5;
 
             while self.state.read().running {
                 if self.state.read().paused {
-                    self.update_status("⏸️ Bot paused - Waiting for resume...");
-                    thread::sleep(Duration::from_millis(500));
+                    self.update_status(t("status.paused_waiting"));
+                    self.clock.sleep(Duration::from_millis(500));
+                    continue;
+                }
+
+                if self.wait_while_display_missing()
+                    || self.wait_while_screen_blank()
+                    || self.wait_while_loading()
+                {
                     continue;
                 }
 
-                let operation_start = Instant::now();
-                let success = match self.fish_once() {
+                let operation_start = self.clock.now();
+                let (success, caught) = match self.fish_once() {
                     Ok(caught) => {
                         consecutive_errors = 0;
                         if caught {
                             self.handle_successful_catch();
+                            if self.session_goal_reached() {
+                                self.stop(StopReason::TargetReached);
+                                return;
+                            }
                         }
-                        true
+                        (true, caught)
+                    }
+                    Err(e)
+                        if matches!(
+                            e.downcast_ref::<BotError>(),
+                            Some(BotError::FailsafeTriggered)
+                        ) =>
+                    {
+                        // A deliberate stop, not a failure: leave the metrics alone
+                        self.handle_failsafe();
+                        return;
                     }
                     Err(e) => {
                         consecutive_errors += 1;
                         self.handle_error(&e, consecutive_errors);
 
                         if consecutive_errors >= max_consecutive_errors {
-                            self.update_status(
-                                "❌ Too many consecutive errors - Stopping for safety",
-                            );
-                            break;
+                            self.update_status(t("status.too_many_errors"));
+                            self.stop(StopReason::TooManyErrors);
+                            return;
                         }
-                        false
+                        (false, false)
                     }
                 };
 
                 // Record performance
                 let mut monitor = self.performance_monitor.lock().unwrap();
-                monitor.record_operation(operation_start.elapsed(), success);
+                monitor.record_operation(self.clock.since(operation_start), success);
+                monitor.record_cast(caught);
                 drop(monitor);
 
+                self.check_success_rate_guard();
+
                 // Update statistics
                 self.update_runtime_stats();
 
                 // Check for periodic screenshot
                 self.webhook.check_periodic_screenshot(&self.detector);
 
+                // Time-based feeding when hunger can't be read
+                self.check_timed_feed();
+
+                // Periodic currency reading
+                self.check_gold();
+
+                // Move on when the current spot's time or catch budget is used up
+                self.check_spot_rotation();
+
+                self.checkpoint_session_if_due();
+
                 // Brief pause between cycles
-                thread::sleep(Duration::from_millis(50));
+                self.clock.sleep(Duration::from_millis(50));
+            }
+
+            self.update_status(t("status.session_completed"));
+        }
+
+        /// Saves the session's progress every few catches or minutes so a crash
+        /// can be resumed from `session.json`.
+        fn checkpoint_session_if_due(&self) {
+            let mut state = self.state.write();
+            let now = self.clock.now();
+            let due = match state.last_checkpoint {
+                Some((at, fish)) => {
+                    state.fish_count >= fish + CHECKPOINT_EVERY_FISH
+                        || now.duration_since(at) >= CHECKPOINT_INTERVAL
+                }
+                None => true,
+            };
+            if !due || !state.running {
+                return;
+            }
+            let Some(start_time) = state.start_time else {
+                return;
+            };
+            state.last_checkpoint = Some((now, state.fish_count));
+
+            let checkpoint = SessionCheckpoint {
+                saved_at: Local::now().to_rfc3339(),
+                fish: state.fish_count,
+                feeds: state.session_feeds,
+                runtime_seconds: self.clock.since(start_time).as_secs(),
+                best_streak: state.session_best_streak,
+                current_streak: state.current_streak,
+                errors: state.errors_count,
+            };
+            drop(state);
+
+            if let Err(e) = checkpoint.save() {
+                log::warn!("Failed to save session checkpoint: {}", e);
+            }
+        }
+
+        /// Starts a session that continues counting from a crashed run's checkpoint.
+        /// Returns whether a new run loop was started.
+        pub fn resume_session(&self, checkpoint: &SessionCheckpoint) -> bool {
+            self.start_with(Some(checkpoint))
+        }
+
+        /// The current spot's cast position, falling back to the global one.
+        fn cast_position(&self) -> Option<(i32, i32)> {
+            let config = self.config.read();
+            let spot_position = if config.spot_rotation_enabled {
+                let current = self.state.read().current_spot;
+                config
+                    .spots
+                    .get(current)
+                    .and_then(|spot| spot.cast_position)
+            } else {
+                None
+            };
+            spot_position.or(config.cast_click_pos)
+        }
+
+        /// Holds the loop while the target display is disconnected and carries on
+        /// once it is back. Returns whether it had to wait.
+        fn wait_while_display_missing(&self) -> bool {
+            let mut waited = false;
+            while self.state.read().running
+                && !self.state.read().paused
+                && !self.detector.source_available()
+            {
+                if !waited {
+                    self.update_phase(FishingPhase::Idle);
+                    self.update_status(t("status.display_disconnected"));
+                    waited = true;
+                }
+                self.clock.sleep(Duration::from_secs(2));
+            }
+
+            if waited {
+                self.update_status(t("status.display_reconnected"));
+            }
+            waited
+        }
+
+        /// Holds the loop while captures come back blank instead of clicking into nothing.
+        /// Returns whether it had to wait.
+        fn wait_while_screen_blank(&self) -> bool {
+            let config = self.config.read();
+            if !config.blank_frame_pause_enabled {
+                return false;
+            }
+            let ratio = config.blank_frame_ratio / 100.0;
+            drop(config);
+
+            let mut waited = false;
+            while self.state.read().running && !self.state.read().paused {
+                // The whole display, since the bite region alone goes dark at night
+                let blank = self
+                    .detector
+                    .take_full_screenshot()
+                    .map(|image| AdvancedDetector::is_blank(&image, ratio))
+                    .unwrap_or(false);
+                if !blank {
+                    break;
+                }
+
+                if !waited {
+                    self.update_phase(FishingPhase::Idle);
+                    self.update_status(t("status.screen_blank"));
+                    waited = true;
+                }
+                self.clock.sleep(Duration::from_secs(2));
+            }
+
+            if waited {
+                self.update_status(t("status.screen_restored"));
+            }
+            waited
+        }
+
+        /// Holds the loop while a loading or teleport screen covers the game, since
+        /// clicks are lost and regions show the wrong content. Returns whether it
+        /// had to wait.
+        fn wait_while_loading(&self) -> bool {
+            let config = self.config.read();
+            if !config.loading_check_enabled {
+                return false;
+            }
+            let region = config.loading_region;
+            let [r, g, b] = config.loading_color;
+            let ratio = config.loading_ratio / 100.0;
+            let tolerance = config.color_tolerance;
+            drop(config);
+            let loading_color = Color { r, g, b };
+
+            let mut waited = false;
+            while self.state.read().running && !self.state.read().paused {
+                let loading = self
+                    .detector
+                    .get_screenshot(region)
+                    .map(|image| {
+                        AdvancedDetector::color_coverage(&image, &loading_color, tolerance) >= ratio
+                    })
+                    .unwrap_or(false);
+                if !loading {
+                    break;
+                }
+
+                if !waited {
+                    self.update_phase(FishingPhase::Idle);
+                    self.update_status(t("status.loading_screen"));
+                    waited = true;
+                }
+                self.clock.sleep(Duration::from_secs(1));
             }
 
-            self.webhook.stop();
-            self.update_status("🏁 Fishing session completed");
+            if waited {
+                self.update_status(t("status.loading_cleared"));
+            }
+            waited
         }
 
         fn fish_once(&self) -> Result<bool> {
             // Cast rod
             self.update_phase(FishingPhase::Casting);
-            self.update_status("🎯 Casting fishing line...");
+            self.dismiss_popup()?;
+            self.ensure_rod_equipped()?;
+            self.update_status(t("status.casting"));
 
+            let cast_position = self.cast_position();
             if let Ok(mut input) = self.input.lock() {
-                input.click()?;
+                match cast_position {
+                    Some((x, y)) => input.click_at(x, y)?,
+                    None => input.click()?,
+                }
             }
-            thread::sleep(Duration::from_millis(100));
+            self.emit(BotEvent::Cast);
+            let cast_settle = self.config.read().cast_settle_ms;
+            self.clock.sleep(Duration::from_millis(cast_settle));
 
             // Wait for bite
             self.update_phase(FishingPhase::WaitingForBite);
@@ -1408,6 +5276,9 @@ mod bot {
             if !bite_detected {
                 return Ok(false); // Timeout, try again
             }
+            self.emit(BotEvent::BiteDetected);
+
+            self.wait_for_minigame_start()?;
 
             // Reel in fish
             self.update_phase(FishingPhase::Reeling);
@@ -1421,95 +5292,355 @@ mod bot {
             Ok(false)
         }
 
-        fn wait_for_bite(&self) -> Result<bool> {
+        /// Clears a level-up or skill popup covering the game with the configured
+        /// click or key, so it can't swallow the cast.
+        fn dismiss_popup(&self) -> Result<()> {
             let config = self.config.read();
-            let timeout = config.calculate_max_bite_time();
-            let red_region = config.red_region;
-            let detection_interval = Duration::from_millis(config.detection_interval_ms);
+            if !config.popup_check_enabled {
+                return Ok(());
+            }
+            let popup_region = config.popup_region;
+            let [r, g, b] = config.popup_color;
+            let dismiss = config.popup_dismiss;
             drop(config);
-            let start_time = Instant::now();
-
-            self.update_status(&format!(
-                "🎣 Waiting for fish bite... (Timeout: {:.0}s)",
-                timeout.as_secs_f32()
-            ));
 
-            while self.state.read().running && !self.state.read().paused {
-                if start_time.elapsed() > timeout {
-                    self.update_status("⏱️ No bite detected - Recasting...");
-                    return Ok(false);
-                }
+            if !self
+                .detector
+                .detect_color(popup_region, &Color { r, g, b })?
+            {
+                return Ok(());
+            }
 
-                if self
-                    .detector
-                    .detect_color(red_region, &Color::RED_EXCLAMATION)?
-                {
-                    self.update_status("🎯 Fish bite detected! Reeling in...");
-                    return Ok(true);
+            self.update_status(t("status.popup_detected"));
+            if let Ok(mut input) = self.input.lock() {
+                match dismiss {
+                    PopupDismiss::Click { x, y } => input.click_at(x, y)?,
+                    PopupDismiss::Key(key) => input.press_key(key)?,
                 }
-
-                thread::sleep(detection_interval);
             }
-
-            Ok(false)
+            self.state.write().auto_recoveries += 1;
+            self.clock.sleep(Duration::from_millis(500));
+            Ok(())
         }
 
-        fn reel_in_fish(&self) -> Result<bool> {
+        /// Checks the hotbar for the equipped rod and re-equips it if it has dropped.
+        fn ensure_rod_equipped(&self) -> Result<()> {
             let config = self.config.read();
-            let start_time = Instant::now();
-            let max_duration = Duration::from_millis(config.max_fishing_timeout_ms);
-            let yellow_region = config.yellow_region;
-            let autoclick_interval = Duration::from_millis(config.autoclick_interval_ms);
-            let confirm_delay = Duration::from_millis(config.detection_interval_ms);
+            if !config.rod_check_enabled {
+                return Ok(());
+            }
+            let rod_region = config.rod_region;
+            let [r, g, b] = config.rod_equipped_color;
             drop(config);
+            let rod_color = Color { r, g, b };
 
-            while self.state.read().running && !self.state.read().paused {
-                if start_time.elapsed() > max_duration {
-                    self.update_status("⏱️ Reeling timeout - Fish got away...");
-                    return Ok(false);
+            const MAX_EQUIP_ATTEMPTS: u32 = 2;
+            for _ in 0..MAX_EQUIP_ATTEMPTS {
+                if self.detector.detect_color(rod_region, &rod_color)? {
+                    return Ok(());
                 }
 
-                // Auto-click
+                self.update_status(t("status.rod_missing"));
                 if let Ok(mut input) = self.input.lock() {
-                    input.click()?;
-                }
-
-                // Check if fish is caught
-                if self
-                    .detector
-                    .detect_color(yellow_region, &Color::YELLOW_CAUGHT)?
-                {
-                    if self.confirm_catch(yellow_region, confirm_delay)? {
-                        self.update_status("🎉 Fish successfully caught!");
-                        return Ok(true);
-                    }
+                    input.press_key('5')?;
                 }
-
-                thread::sleep(autoclick_interval);
+                self.state.write().auto_recoveries += 1;
+                self.clock.sleep(Duration::from_millis(500));
             }
 
-            Ok(false)
+            if self.detector.detect_color(rod_region, &rod_color)? {
+                return Ok(());
+            }
+            Err(anyhow!("Rod still missing after re-equip attempts"))
         }
 
-        fn confirm_catch(
-            &self,
-            region: config::Region,
+        fn wait_for_bite(&self) -> Result<bool> {
+            let config = self.config.read();
+            let timeout = config.calculate_max_bite_time();
+            let red_region = config.red_region;
+            let bite_scan_interval = Duration::from_millis(config.bite_scan_interval_ms);
+            let detection_mode = config.bite_detection_mode;
+            let motion_threshold = config.motion_delta_threshold;
+            let adaptive_timing = config.adaptive_timing;
+            let scan_period =
+                Duration::from_secs_f32(1.0 / config.target_scan_rate_hz.max(1) as f32);
+            let target_hz = if adaptive_timing {
+                config.target_scan_rate_hz.max(1) as f32
+            } else {
+                1000.0 / config.bite_scan_interval_ms.max(1) as f32
+            };
+            drop(config);
+            let start_time = self.clock.now();
+
+            if detection_mode == BiteDetectionMode::MotionDelta {
+                self.detector.reset_motion_baseline();
+            }
+
+            self.update_status(&tf(
+                "status.waiting_for_bite",
+                &[("timeout", &format!("{:.0}", timeout.as_secs_f32()))],
+            ));
+
+            while self.state.read().running && !self.state.read().paused {
+                let iteration_start = self.clock.now();
+                if self.clock.since(start_time) > timeout {
+                    self.update_status(t("status.no_bite"));
+                    return Ok(false);
+                }
+
+                self.record_scan(target_hz);
+                let bite = match detection_mode {
+                    BiteDetectionMode::Color => {
+                        let bite = self
+                            .detector
+                            .detect_color(red_region, &Color::RED_EXCLAMATION)?;
+                        self.state.write().bite_match_pixels = self.detector.last_match_count();
+                        bite
+                    }
+                    BiteDetectionMode::MotionDelta => {
+                        self.detector.detect_motion(red_region, motion_threshold)?
+                    }
+                };
+
+                if bite {
+                    if detection_mode == BiteDetectionMode::Color {
+                        let pixels = self.state.read().bite_match_pixels;
+                        self.record_match_count(MatchKind::Bite, pixels);
+                    }
+                    self.update_status(t("status.bite_detected"));
+                    self.schedule_anti_afk();
+                    return Ok(true);
+                }
+
+                self.check_anti_afk();
+
+                if adaptive_timing {
+                    self.sleep_remaining(iteration_start, scan_period);
+                } else {
+                    self.clock.sleep(bite_scan_interval);
+                }
+            }
+
+            Ok(false)
+        }
+
+        /// Holds off reeling until the minigame's start indicator shows, since clicks
+        /// before then are ignored. Gives up after the timeout and reels anyway.
+        fn wait_for_minigame_start(&self) -> Result<()> {
+            let config = self.config.read();
+            if !config.minigame_start_check_enabled {
+                return Ok(());
+            }
+            let region = config.minigame_start_region;
+            let [r, g, b] = config.minigame_start_color;
+            let timeout = Duration::from_millis(config.minigame_start_timeout_ms);
+            let scan_interval = Duration::from_millis(config.bite_scan_interval_ms);
+            drop(config);
+            let start_color = Color { r, g, b };
+            let start_time = self.clock.now();
+
+            while self.state.read().running && !self.state.read().paused {
+                if self.detector.detect_color(region, &start_color)? {
+                    return Ok(());
+                }
+                if self.clock.since(start_time) > timeout {
+                    self.update_status(t("status.minigame_not_seen"));
+                    return Ok(());
+                }
+                self.clock.sleep(scan_interval);
+            }
+
+            Ok(())
+        }
+
+        fn reel_in_fish(&self) -> Result<bool> {
+            let config = self.config.read();
+            let start_time = self.clock.now();
+            let max_duration = Duration::from_millis(config.max_fishing_timeout_ms);
+            let yellow_region = config.yellow_region;
+            let mut click_intervals = if config.reel_click_pattern_ms.is_empty() {
+                vec![config.autoclick_interval_ms]
+            } else {
+                config.reel_click_pattern_ms.clone()
+            }
+            .into_iter()
+            .cycle();
+            let scan_interval = Duration::from_millis(config.reel_scan_interval_ms);
+            let target_hz = 1000.0 / config.reel_scan_interval_ms.max(1) as f32;
+            let reel_click_pos = if config.reel_click_in_region {
+                let [fx, fy] = config.reel_click_offset;
+                Some(detection::region_point_on_desktop(
+                    &config,
+                    yellow_region.point_at(fx, fy),
+                ))
+            } else {
+                config.reel_click_pos
+            };
+            let adaptive_timing = config.adaptive_timing;
+            drop(config);
+            let mut last_scan: Option<Instant> = None;
+
+            while self.state.read().running && !self.state.read().paused {
+                let iteration_start = self.clock.now();
+                if self.clock.since(start_time) > max_duration {
+                    self.update_status(t("status.reel_timeout"));
+                    self.record_reel_timeout();
+                    return Ok(false);
+                }
+
+                // Auto-click
+                if let Ok(mut input) = self.input.lock() {
+                    match reel_click_pos {
+                        Some((x, y)) => input.click_at(x, y)?,
+                        None => input.click()?,
+                    }
+                }
+
+                // Check if fish is caught, at its own (usually slower) rate
+                let scan_due =
+                    !matches!(last_scan, Some(at) if self.clock.since(at) < scan_interval);
+                let catch_seen = if scan_due {
+                    last_scan = Some(self.clock.now());
+                    self.record_scan(target_hz);
+                    let seen = self.catch_visible(yellow_region)?;
+                    self.state.write().catch_match_pixels = self.detector.last_match_count();
+                    seen
+                } else {
+                    false
+                };
+                if catch_seen && self.confirm_catch(yellow_region, scan_interval)? {
+                    let pixels = self.state.read().catch_match_pixels;
+                    self.record_match_count(MatchKind::Catch, pixels);
+                    self.update_status(t("status.catch_success"));
+                    self.state.write().consecutive_reel_timeouts = 0;
+                    return Ok(true);
+                }
+
+                let click_interval = Duration::from_millis(click_intervals.next().unwrap_or(70));
+                if adaptive_timing {
+                    self.sleep_remaining(iteration_start, click_interval);
+                } else {
+                    self.clock.sleep(click_interval);
+                }
+            }
+
+            Ok(false)
+        }
+
+        /// Picks the next anti-AFK deadline, jittered by ±15% so nudges aren't periodic.
+        fn schedule_anti_afk(&self) {
+            let idle_secs = self.config.read().anti_afk_idle_secs.max(1) as f32;
+            let jitter: f32 = self.rng.gen_range(0.85..=1.15);
+            self.state.write().next_anti_afk_at =
+                Some(self.clock.now() + Duration::from_secs_f32(idle_secs * jitter));
+        }
+
+        fn check_anti_afk(&self) {
+            if !self.config.read().anti_afk_enabled {
+                return;
+            }
+
+            let deadline = self.state.read().next_anti_afk_at;
+            match deadline {
+                None => {
+                    self.schedule_anti_afk();
+                    return;
+                }
+                Some(deadline) if self.clock.now() < deadline => return,
+                Some(_) => {}
+            }
+
+            self.update_status(t("status.anti_afk"));
+            if let Ok(mut input) = self.input.lock() {
+                // Random hold so nudges don't all look identical
+                let hold = Duration::from_millis(self.rng.gen_range(30..=90));
+                input.anti_afk_nudge(hold).ok();
+            }
+            self.schedule_anti_afk();
+        }
+
+        /// Sleeps only for whatever is left of `period` after the work done since
+        /// `iteration_start`, keeping the effective rate independent of capture latency.
+        fn sleep_remaining(&self, iteration_start: Instant, period: Duration) {
+            let remaining = period.saturating_sub(self.clock.since(iteration_start));
+            if !remaining.is_zero() {
+                self.clock.sleep(remaining);
+            }
+        }
+
+        fn confirm_catch(
+            &self,
+            region: config::Region,
             confirm_delay: Duration,
         ) -> Result<bool> {
-            thread::sleep(confirm_delay);
-            self.detector.detect_color(region, &Color::YELLOW_CAUGHT)
+            self.clock.sleep(confirm_delay);
+            self.catch_visible(region)
+        }
+
+        /// Yellow "caught" text with no red exclamation in the same capture; a
+        /// lingering exclamation can partly pass the yellow check on its own.
+        fn catch_visible(&self, region: config::Region) -> Result<bool> {
+            let found = self
+                .detector
+                .detect_colors(region, &[Color::YELLOW_CAUGHT, Color::RED_EXCLAMATION])?;
+            Ok(found[0] && !found[1])
+        }
+
+        fn play_sound(&self, cue: sound::Cue, enabled: fn(&BotConfig) -> bool) {
+            let config = self.config.read();
+            if enabled(&config) {
+                sound::play(cue, config.sound_volume);
+            }
+        }
+
+        fn release_input(&self) {
+            if let Ok(mut input) = self.input.lock() {
+                if let Err(e) = input.release_all() {
+                    log::warn!("Failed to release held input: {}", e);
+                }
+            }
+        }
+
+        fn reset_rod(&self) {
+            let steps = self.config.read().rod_reset_sequence.clone();
+            if let Ok(mut input) = self.input.lock() {
+                input.run_sequence(&steps).ok();
+            }
+        }
+
+        fn eat_food(&self) {
+            let steps = self.config.read().feed_sequence.clone();
+            if let Ok(mut input) = self.input.lock() {
+                input.run_sequence(&steps).ok();
+            }
         }
 
         fn handle_successful_catch(&self) {
             // Reset rod
-            if let Ok(mut input) = self.input.lock() {
-                input.reset_rod().ok();
+            self.reset_rod();
+            self.play_sound(sound::Cue::Catch, |config| config.sound_on_catch);
+
+            // Let the catch animation and inventory update settle before recasting
+            let post_catch_delay = self.config.read().post_catch_delay_ms;
+            if post_catch_delay > 0 {
+                self.clock.sleep(Duration::from_millis(post_catch_delay));
             }
 
             // Update counts
             let mut state = self.state.write();
             state.fish_count += 1;
             state.current_streak += 1;
+            state.spot_fish += 1;
+            state.recent_catches.push_back(self.clock.now());
+            let caught_at = Local::now();
+            let since_previous = state
+                .catch_log
+                .back()
+                .and_then(|previous| (caught_at - *previous).to_std().ok());
+            state.catch_log.push_back(caught_at);
+            if state.catch_log.len() > MAX_CATCH_LOG {
+                state.catch_log.pop_front();
+            }
 
             if state.current_streak > state.session_best_streak {
                 state.session_best_streak = state.current_streak;
@@ -1517,75 +5648,211 @@ mod bot {
 
             let fish_count = state.fish_count;
             drop(state);
+            self.emit(BotEvent::Caught { fish_count });
+
+            if self.config.read().catch_log_csv {
+                if let Err(e) = CatchLog::append(caught_at, fish_count, since_previous) {
+                    log::warn!("Failed to append to catch log: {}", e);
+                }
+            }
 
             // Update lifetime stats
             let mut stats = self.lifetime_stats.write();
             stats.add_fish(1);
             drop(stats);
 
-            self.update_status(&format!(
-                "🐟 Fish #{} caught! Current streak: {}",
-                fish_count,
-                self.state.read().current_streak
+            let streak = self.state.read().current_streak;
+            self.update_status(&tf(
+                "status.fish_caught",
+                &[("count", &fish_count), ("streak", &streak)],
             ));
 
             // Send milestone notifications
-            if fish_count % 10 == 0 {
-                self.webhook.send_message(format!(
-                    "🎉 Milestone Reached! {} fish caught this session!",
-                    fish_count
-                ));
+            let (milestone_interval, milestone_message) = {
+                let config = self.config.read();
+                (
+                    config.milestone_interval as u64,
+                    config
+                        .milestone_message_template
+                        .replace("{count}", &fish_count.to_string()),
+                )
+            };
+            if self.config.read().notify_on_milestone
+                && milestone_interval > 0
+                && fish_count % milestone_interval == 0
+            {
+                if let Some(message) = self
+                    .webhook
+                    .try_alert(AlertKind::Milestone, milestone_message)
+                {
+                    self.webhook.send_message(message);
+                }
             }
 
             // Check if need to feed
-            if fish_count % self.config.read().fish_per_feed as u64 == 0 {
+            if self.state.read().next_feed_at_fish.is_none() {
+                self.schedule_feed(0);
+            }
+            let feed_due = self
+                .state
+                .read()
+                .next_feed_at_fish
+                .is_some_and(|at| fish_count >= at);
+            if feed_due {
                 self.check_and_feed();
+                self.schedule_feed(fish_count);
             }
         }
 
-        fn check_and_feed(&self) {
+        /// Whether a blind feed is allowed yet under `safety_feed_cooldown_secs`.
+        fn safety_feed_allowed(&self) -> bool {
+            let cooldown = Duration::from_secs(self.config.read().safety_feed_cooldown_secs as u64);
+            !matches!(
+                self.state.read().last_safety_feed,
+                Some(at) if self.clock.since(at) < cooldown
+            )
+        }
+
+        /// Picks the catch count for the next hunger check, `fish_per_feed` after
+        /// `from` give or take `feed_jitter`.
+        fn schedule_feed(&self, from: u64) {
+            let config = self.config.read();
+            let base = config.fish_per_feed.max(1) as i64;
+            let jitter = config
+                .feed_jitter
+                .min(config.fish_per_feed.saturating_sub(1)) as i64;
+            drop(config);
+
+            let gap = if jitter == 0 {
+                base
+            } else {
+                self.rng.gen_range(base - jitter..=base + jitter)
+            };
+            self.state.write().next_feed_at_fish = Some(from + gap as u64);
+        }
+
+        /// Feeds on a wall-clock timer while Tesseract is missing, since hunger
+        /// can't be read to drive catch-count feeding.
+        fn check_timed_feed(&self) {
+            if ocr::tesseract_error().is_some() {
+                self.feed_if_timer_due();
+            }
+        }
+
+        fn feed_if_timer_due(&self) {
+            let interval = Duration::from_secs(self.config.read().safety_feed_cooldown_secs as u64)
+                .max(MIN_TIMED_FEED_INTERVAL);
+            let state = self.state.read();
+            let last_feed = state.last_safety_feed.or(state.start_time);
+            drop(state);
+            if matches!(last_feed, Some(at) if self.clock.since(at) < interval) {
+                return;
+            }
+
             self.update_phase(FishingPhase::Feeding);
-            self.update_status("🍖 Checking hunger level...");
+            self.update_status(t("status.feeding_on_timer"));
+            self.state.write().last_safety_feed = Some(self.clock.now());
+            self.eat_food();
+            self.emit(BotEvent::Fed { hunger: None });
+            self.count_feed();
+        }
 
-            let hunger_region = self.config.read().hunger_region;
-            if let Ok(screenshot) = self.detector.get_screenshot(hunger_region) {
-                let mut ocr = self.ocr.lock().unwrap();
-                let hunger = ocr.read_hunger(&screenshot).unwrap_or(None);
+        fn count_feed(&self) {
+            self.state.write().session_feeds += 1;
+            self.lifetime_stats.write().add_feed();
+        }
 
-                let mut state = self.state.write();
-                state.last_hunger = hunger;
-                drop(state);
+        fn check_and_feed(&self) {
+            // The run loop's timer feeds instead while hunger can't be read
+            if ocr::tesseract_error().is_some() {
+                return;
+            }
+
+            self.update_phase(FishingPhase::Feeding);
+            self.update_status(t("status.checking_hunger"));
 
-                if let Some(h) = hunger {
-                    if h < 100 {
-                        self.update_status(&format!("🍖 Hunger at {}% - Feeding character...", h));
+            let config = self.config.read();
+            let hunger_region = config.hunger_region;
+            let ocr_opts = OcrOpts::hunger(&config);
+            drop(config);
+            let hunger = match self.detector.get_screenshot(hunger_region) {
+                Ok(screenshot) => self
+                    .ocr
+                    .lock()
+                    .unwrap()
+                    .read_number(&screenshot, ocr_opts)
+                    .unwrap_or(None)
+                    .map(|value| value as u32),
+                Err(e) => {
+                    log::warn!("Hunger screenshot failed: {}", e);
+                    None
+                }
+            };
 
-                        if let Ok(mut input) = self.input.lock() {
-                            input.eat_food().ok();
-                        }
+            let mut state = self.state.write();
+            state.last_hunger = hunger;
+            let failures = if hunger.is_some() {
+                state.hunger_ocr_failures = 0;
+                0
+            } else {
+                state.hunger_ocr_failures += 1;
+                state.hunger_ocr_failures
+            };
+            drop(state);
 
-                        // Update feed count
-                        let mut stats = self.lifetime_stats.write();
-                        stats.add_feed();
-                        drop(stats);
+            if let Some(h) = hunger {
+                if h < 100 {
+                    self.update_status(&tf("status.feeding", &[("hunger", &h)]));
 
-                        self.webhook
-                            .send_message(format!("🍖 Fed character (Hunger was {}%)", h));
-                        self.update_status("✅ Successfully fed character!");
-                    } else {
-                        self.update_status(&format!("✅ Hunger at {}% - No feeding needed", h));
-                    }
+                    self.eat_food();
+                    self.emit(BotEvent::Fed { hunger: Some(h) });
+                    self.count_feed();
+
+                    self.webhook
+                        .send_message(format!("🍖 Fed character (Hunger was {}%)", h));
+                    self.update_status(t("status.fed"));
                 } else {
-                    // OCR failed, feed anyway to be safe
-                    self.update_status("⚠️ Could not read hunger - Feeding to be safe...");
-                    if let Ok(mut input) = self.input.lock() {
-                        input.eat_food().ok();
-                    }
-                    self.webhook.send_message(
-                        "⚠️ OCR failed - Fed character as safety measure".to_string(),
-                    );
+                    self.update_status(&tf("status.no_feeding_needed", &[("hunger", &h)]));
                 }
+            } else if failures >= HUNGER_OCR_FAILURE_LIMIT {
+                // Repeated failures point at a wrong region; feeding blindly only wastes food
+                self.update_status(t("status.hunger_ocr_failing"));
+                if failures == HUNGER_OCR_FAILURE_LIMIT {
+                    self.webhook.send_message(format!(
+                        "⚠️ Hunger OCR failed {} times in a row - check the hunger region. Feeding is paused until a read succeeds.",
+                        failures
+                    ));
+                }
+            } else if !self.safety_feed_allowed() {
+                self.update_status(t("status.safety_feed_cooldown"));
+            } else {
+                // OCR failed, feed anyway to be safe
+                self.update_status(t("status.hunger_unreadable"));
+                self.state.write().last_safety_feed = Some(self.clock.now());
+                self.eat_food();
+                self.emit(BotEvent::Fed { hunger: None });
+                self.count_feed();
+                self.webhook
+                    .send_message("⚠️ OCR failed - Fed character as safety measure".to_string());
+            }
+        }
+
+        fn session_goal_reached(&self) -> bool {
+            let config = self.config.read();
+            config.stop_at_session_goal
+                && config.session_fish_goal > 0
+                && self.state.read().fish_count >= config.session_fish_goal as u64
+        }
+
+        fn handle_failsafe(&self) {
+            if self.config.read().notify_on_error {
+                self.webhook.send_message(
+                    "🛑 Failsafe triggered: mouse moved to the top-left corner, bot stopped"
+                        .to_string(),
+                );
             }
+
+            self.stop(StopReason::Failsafe);
         }
 
         fn handle_error(&self, error: &anyhow::Error, consecutive_count: u32) {
@@ -1596,1008 +5863,4001 @@ mod bot {
             state.current_streak = 0; // Reset streak on error
             drop(state);
 
-            let error_msg = format!(
-                "⚠️ Error #{}: {} (Consecutive: {})",
-                self.state.read().errors_count,
-                error,
-                consecutive_count
+            self.lifetime_stats.write().add_error();
+
+            let errors_count = self.state.read().errors_count;
+            let error_msg = tf(
+                "status.error",
+                &[
+                    ("number", &errors_count),
+                    ("error", error),
+                    ("consecutive", &consecutive_count),
+                ],
             );
 
             self.update_status(&error_msg);
+            self.emit(BotEvent::Error {
+                message: error.to_string(),
+            });
+
+            let mut state = self.state.write();
+            state
+                .recent_errors
+                .push_back(format!("{} {}", Local::now().format("%H:%M:%S"), error));
+            while state.recent_errors.len() > MAX_RECENT_ERRORS {
+                state.recent_errors.pop_front();
+            }
+            drop(state);
 
             // Send error notification for critical errors
-            if consecutive_count >= 3 {
-                self.webhook
-                    .send_message(format!("🚨 Critical Error Alert: {}", error_msg));
+            if consecutive_count >= 3 && self.config.read().notify_on_error {
+                let alert = format!("🚨 Critical Error Alert: {}", error_msg);
+                if let Some(alert) = self.webhook.try_alert(AlertKind::Error, alert) {
+                    if !self.send_error_screenshot(&alert) {
+                        self.webhook.send_message(alert);
+                    }
+                }
             }
 
             // Recovery delay
             let delay = std::cmp::min(1000 * consecutive_count as u64, 5000);
-            thread::sleep(Duration::from_millis(delay));
+            self.clock.sleep(Duration::from_millis(delay));
         }
 
-        fn update_runtime_stats(&self) {
-            let mut state = self.state.write();
+        /// Attaches what the screen looked like to an error alert. Errors come in
+        /// bursts, so at most one capture is sent per cooldown.
+        fn send_error_screenshot(&self, alert: &str) -> bool {
+            if !self.config.read().error_screenshot_enabled {
+                return false;
+            }
 
-            if let Some(start_time) = state.start_time {
-                let elapsed = start_time.elapsed();
-                let hours = elapsed.as_secs_f32() / 3600.0;
+            let now = self.clock.now();
+            let mut state = self.state.write();
+            if state
+                .last_error_screenshot
+                .is_some_and(|at| now.duration_since(at) < ERROR_SCREENSHOT_COOLDOWN)
+            {
+                return false;
+            }
+            state.last_error_screenshot = Some(now);
+            drop(state);
 
-                if hours > 0.0 {
-                    state.fish_per_hour = state.fish_count as f32 / hours;
-                }
+            self.webhook
+                .send_screen_capture(alert.to_string(), &self.detector)
+        }
 
-                // Calculate uptime percentage (simplified)
-                let total_time = elapsed.as_secs_f32();
-                let error_time = state.errors_count as f32 * 2.0; // Assume 2 seconds per error
-                state.uptime_percentage = ((total_time - error_time) / total_time * 100.0).max(0.0);
+        fn check_success_rate_guard(&self) {
+            let config = self.config.read();
+            if !config.low_success_pause_enabled {
+                return;
             }
+            let floor = config.low_success_rate_floor;
+            let window = config.low_success_window as usize;
+            drop(config);
+
+            let mut monitor = self.performance_monitor.lock().unwrap();
+            let rate = match monitor.get_rolling_catch_rate(window) {
+                Some(rate) if rate < floor => rate,
+                _ => return,
+            };
+            // Start a fresh window so resuming doesn't immediately re-trigger
+            monitor.clear_recent_casts();
+            drop(monitor);
+
+            let message = tf(
+                "status.low_success",
+                &[("rate", &format!("{:.0}", rate)), ("window", &window)],
+            );
+            self.pause_for_problem(&message);
         }
 
-        fn update_status(&self, status: &str) {
+        /// Counts a reel that never saw the catch marker, pausing once
+        /// `max_reel_timeouts` happen in a row.
+        fn record_reel_timeout(&self) {
+            let limit = self.config.read().max_reel_timeouts;
             let mut state = self.state.write();
-            state.status = status.to_string();
+            state.consecutive_reel_timeouts += 1;
+            if limit == 0 || state.consecutive_reel_timeouts < limit {
+                return;
+            }
+            // Resuming starts a fresh count
+            state.consecutive_reel_timeouts = 0;
+            drop(state);
+
+            self.pause_for_problem(&tf("status.reel_timeouts", &[("count", &limit)]));
         }
 
-        fn update_phase(&self, phase: FishingPhase) {
+        /// Pauses from inside the loop with `message` as the status and alerts
+        /// the webhook.
+        fn pause_for_problem(&self, message: &str) {
             let mut state = self.state.write();
-            state.current_phase = phase;
+            state.paused = true;
+            state.status = self.present(message);
+            drop(state);
+            self.release_input();
+
+            if self.config.read().notify_on_error {
+                self.webhook
+                    .send_message(format!("🚨 {} - check your regions and game UI", message));
+            }
         }
-    }
 
-    impl Clone for AdvancedFishingBot {
-        fn clone(&self) -> Self {
-            Self {
-                config: self.config.clone(),
-                state: self.state.clone(),
-                lifetime_stats: self.lifetime_stats.clone(),
-                detector: self.detector.clone(),
-                input: Arc::new(Mutex::new(RobloxInputController::new(
-                    self.config.read().failsafe_enabled,
-                ))),
-                webhook: self.webhook.clone(),
-                ocr: Arc::new(Mutex::new(
-                    EnhancedOCRHandler::new()
-                        .unwrap_or_else(|_| EnhancedOCRHandler::new().unwrap()),
-                )),
-                performance_monitor: self.performance_monitor.clone(),
+        fn check_gold(&self) {
+            let config = self.config.read();
+            if !config.gold_tracking_enabled || ocr::tesseract_error().is_some() {
+                return;
+            }
+            let gold_region = config.gold_region;
+            let ocr_opts = OcrOpts::gold(&config);
+            let interval = Duration::from_secs(config.gold_check_interval_secs.max(5));
+            drop(config);
+
+            let mut state = self.state.write();
+            if state
+                .last_gold_check
+                .is_some_and(|last| self.clock.since(last) < interval)
+            {
+                return;
+            }
+            state.last_gold_check = Some(self.clock.now());
+            drop(state);
+
+            if let Ok(screenshot) = self.detector.get_screenshot(gold_region) {
+                let gold = self
+                    .ocr
+                    .lock()
+                    .unwrap()
+                    .read_number(&screenshot, ocr_opts)
+                    .unwrap_or(None);
+                if let Some(gold) = gold {
+                    let mut state = self.state.write();
+                    let start = *state.gold_start.get_or_insert(gold);
+                    state.gold_latest = Some(gold);
+                    state.session_gold_delta = Some(gold as i64 - start as i64);
+                }
             }
         }
-    }
-}
 
-// ===== UI MODULE =====
-mod ui {
-    use super::*;
-    use bot::AdvancedFishingBot;
-    use config::{BotConfig, LifetimeStats, Region};
-    use egui::*;
+        /// Sends the daily summary once today's scheduled time has passed. Polled
+        /// from its own thread, so it fires whether or not a session is running.
+        fn check_daily_summary(&self) {
+            let config = self.config.read();
+            if !config.daily_summary_enabled || config.webhook_url.is_empty() {
+                return;
+            }
+            let scheduled = chrono::NaiveTime::from_hms_opt(
+                config.daily_summary_hour.min(23),
+                config.daily_summary_minute.min(59),
+                0,
+            );
+            drop(config);
 
-    pub struct AdvancedFishingBotApp {
-        bot: AdvancedFishingBot,
-        config: BotConfig,
-        show_settings: bool,
-        show_advanced_stats: bool,
-        status_messages: Vec<(chrono::DateTime<chrono::Local>, String)>,
-        last_update: Instant,
-        last_status: String,
-        resolution_presets: HashMap<String, (String, Region, Region, Region)>,
-        window_size: egui::Vec2,
-        scale_factor: f32,
-        #[cfg(target_os = "macos")]
-        safari_url: String,
-    }
+            let now = Local::now();
+            match scheduled {
+                Some(time) if now.time() >= time => {}
+                _ => return,
+            }
 
-    impl AdvancedFishingBotApp {
-        fn add_scaled_space(&self, ui: &mut Ui, base_space: f32) {
-            ui.add_space(base_space * self.scale_factor);
-        }
+            let today = now.date_naive().to_string();
+            let mut stats = self.lifetime_stats.write();
+            let baseline = match &stats.daily_report_baseline {
+                Some(baseline) if baseline.date == today => return,
+                Some(baseline) => baseline.clone(),
+                None => {
+                    // First run: record a starting point and report from tomorrow on
+                    stats.daily_report_baseline = Some(stats.snapshot_baseline(today));
+                    stats.save().ok();
+                    return;
+                }
+            };
 
-        fn scaled_font_size(&self, base_size: f32) -> f32 {
-            (base_size * self.scale_factor).clamp(8.0, 32.0)
-        }
+            let (fish, feeds, runtime, errors, sessions) = stats.deltas_since(&baseline);
+            stats.daily_report_baseline = Some(stats.snapshot_baseline(today.clone()));
+            stats.save().ok();
+            drop(stats);
 
-        fn scaled_button_size(&self, base_width: f32, base_height: f32) -> egui::Vec2 {
-            egui::vec2(
-                (base_width * self.scale_factor).max(80.0),
-                (base_height * self.scale_factor).max(30.0),
-            )
+            let fish_per_hour = if runtime > 0 {
+                fish as f32 * 3600.0 / runtime as f32
+            } else {
+                0.0
+            };
+
+            self.webhook.send_embed(
+                format!("📅 Daily Summary - {}", today),
+                format!("Activity since {}", baseline.date),
+                vec![
+                    ("🐟 Fish Caught".to_string(), fish.to_string()),
+                    ("🍖 Feeds".to_string(), feeds.to_string()),
+                    (
+                        "⏱️ Runtime".to_string(),
+                        format!("{}h {}m", runtime / 3600, (runtime % 3600) / 60),
+                    ),
+                    ("📈 Fish/Hour".to_string(), format!("{:.1}", fish_per_hour)),
+                    ("🏁 Sessions".to_string(), sessions.to_string()),
+                    ("❌ Errors".to_string(), errors.to_string()),
+                ],
+            );
         }
 
-        fn night_sky() -> Color32 {
-            Color32::from_rgb(10, 12, 26)
+        fn update_runtime_stats(&self) {
+            let mut state = self.state.write();
+
+            if let Some(start_time) = state.start_time {
+                let elapsed = self.clock.since(start_time);
+                let hours = elapsed.as_secs_f32() / 3600.0;
+
+                if hours > 0.0 {
+                    state.fish_per_hour = state.fish_count as f32 / hours;
+                }
+
+                // Rate over a trailing window so estimates don't jump on every catch
+                const RATE_WINDOW: Duration = Duration::from_secs(10 * 60);
+                const MIN_RATE_DATA: Duration = Duration::from_secs(2 * 60);
+                while state
+                    .recent_catches
+                    .front()
+                    .is_some_and(|catch| self.clock.since(*catch) > RATE_WINDOW)
+                {
+                    state.recent_catches.pop_front();
+                }
+                let window = elapsed.min(RATE_WINDOW);
+                state.smoothed_fish_per_hour =
+                    if window >= MIN_RATE_DATA && state.recent_catches.len() >= 2 {
+                        Some(state.recent_catches.len() as f32 * 3600.0 / window.as_secs_f32())
+                    } else {
+                        None
+                    };
+
+                // Calculate uptime percentage (simplified)
+                state.uptime_percentage = if elapsed >= MIN_UPTIME_DATA {
+                    let total_time = elapsed.as_secs_f32();
+                    // Assume 2 seconds per error, never more than the whole session
+                    let error_time = (state.errors_count as f32 * 2.0).min(total_time);
+                    Some((total_time - error_time) / total_time * 100.0)
+                } else {
+                    None
+                };
+            }
         }
 
-        fn panel_fill(&self) -> Color32 {
-            Color32::from_rgb(18, 20, 38)
+        fn update_status(&self, status: &str) {
+            let status = self.present(status);
+            let mut state = self.state.write();
+            state.status = status;
         }
 
-        fn rune_border(&self) -> Stroke {
-            Stroke {
-                width: 1.5,
-                color: Color32::from_rgb(108, 86, 171),
+        /// Applies the plain-text preference to a user-facing message.
+        fn present(&self, message: &str) -> String {
+            if self.config.read().plain_text_mode {
+                text::plain_text(message)
+            } else {
+                message.to_string()
             }
         }
 
-        fn gold_glow(&self) -> Color32 {
-            Color32::from_rgb(230, 180, 80)
+        fn update_phase(&self, phase: FishingPhase) {
+            let now = self.clock.now();
+            self.state.write().enter_phase(phase, now);
         }
+    }
 
-        fn arcane_blue(&self) -> Color32 {
-            Color32::from_rgb(70, 130, 200)
+    impl Clone for AdvancedFishingBot {
+        fn clone(&self) -> Self {
+            Self {
+                config: self.config.clone(),
+                state: self.state.clone(),
+                lifetime_stats: self.lifetime_stats.clone(),
+                detector: self.detector.clone(),
+                input: self.input.clone(),
+                webhook: self.webhook.clone(),
+                ocr: Arc::new(Mutex::new(
+                    EnhancedOCRHandler::new()
+                        .unwrap_or_else(|_| EnhancedOCRHandler::new().unwrap()),
+                )),
+                performance_monitor: self.performance_monitor.clone(),
+                clock: self.clock.clone(),
+                rng: self.rng.clone(),
+                loop_active: self.loop_active.clone(),
+                event_subscribers: self.event_subscribers.clone(),
+            }
         }
+    }
 
-        fn arcane_purple(&self) -> Color32 {
-            Color32::from_rgb(120, 80, 200)
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use clock::VirtualClock;
+        use detection::StaticFrameSource;
+        use input::{InputAction, RecordingInput};
+
+        const BACKGROUND: image::Rgba<u8> = image::Rgba([40, 40, 40, 255]);
+        const RED: image::Rgba<u8> = image::Rgba([241, 27, 28, 255]);
+        const YELLOW: image::Rgba<u8> = image::Rgba([255, 255, 0, 255]);
+
+        /// A 60x20 frame: the bite exclamation in the left square, the caught text
+        /// in the right one and nothing in the middle.
+        fn bite_and_catch_frame() -> RgbaImage {
+            RgbaImage::from_fn(60, 20, |x, y| match (x, y) {
+                (5..=10, 5..=10) => RED,
+                (45..=50, 5..=10) => YELLOW,
+                _ => BACKGROUND,
+            })
         }
 
-        fn emerald(&self) -> Color32 {
-            Color32::from_rgb(70, 180, 130)
+        fn test_config() -> BotConfig {
+            BotConfig {
+                red_region: Region::new(0, 0, 20, 20),
+                rod_region: Region::new(20, 0, 20, 20),
+                hunger_region: Region::new(20, 0, 20, 20),
+                yellow_region: Region::new(40, 0, 20, 20),
+                ..BotConfig::default()
+            }
         }
 
-        fn ember_red(&self) -> Color32 {
-            Color32::from_rgb(200, 70, 70)
+        /// Points the data and config directories at a scratch folder, so stats
+        /// saved by the code under test never reach the real files.
+        fn isolate_data_dirs() {
+            static ONCE: std::sync::Once = std::sync::Once::new();
+            ONCE.call_once(|| {
+                let dir = std::env::temp_dir()
+                    .join(format!("arcane-fishing-bot-tests-{}", std::process::id()));
+                std::env::set_var("HOME", &dir);
+                std::env::set_var("XDG_DATA_HOME", dir.join("data"));
+                std::env::set_var("XDG_CONFIG_HOME", dir.join("config"));
+            });
         }
 
-        fn aura_frame(&self, fill: Color32) -> Frame {
-            Frame::none()
-                .fill(fill)
-                .stroke(self.rune_border())
-                .rounding(10.0)
-                .inner_margin(12.0 * self.scale_factor)
+        struct TestBot {
+            bot: AdvancedFishingBot,
+            clock: Arc<VirtualClock>,
+            actions: Arc<Mutex<Vec<InputAction>>>,
         }
 
-        fn phase_progress(&self, phase: &bot::FishingPhase) -> f32 {
-            match phase {
-                bot::FishingPhase::Idle => 0.05,
-                bot::FishingPhase::Casting => 0.25,
-                bot::FishingPhase::WaitingForBite => 0.45,
-                bot::FishingPhase::Reeling => 0.7,
-                bot::FishingPhase::Caught => 1.0,
-                bot::FishingPhase::Feeding => 0.6,
-                bot::FishingPhase::Error => 0.0,
+        fn recording_bot(config: BotConfig, frame: RgbaImage) -> TestBot {
+            isolate_data_dirs();
+            let clock = Arc::new(VirtualClock::default());
+            let input = RecordingInput::new(clock.clone());
+            let actions = input.actions();
+            let bot = AdvancedFishingBot::with_backends(
+                config,
+                LifetimeStats::default(),
+                Box::new(input),
+                Box::new(StaticFrameSource::from_image(frame)),
+                clock.clone(),
+            );
+            TestBot {
+                bot,
+                clock,
+                actions,
             }
         }
 
-        fn render_header(&mut self, ui: &mut Ui) {
-            #[cfg(target_os = "macos")]
-            {
-                self.render_macos_toolbar(ui);
-            }
+        #[test]
+        fn fish_once_casts_then_reels_until_caught() {
+            let config = BotConfig {
+                cast_click_pos: Some((300, 400)),
+                ..test_config()
+            };
+            let TestBot { bot, actions, .. } = recording_bot(config, bite_and_catch_frame());
+            bot.state.write().running = true;
 
-            self.aura_frame(self.panel_fill()).show(ui, |ui| {
-                ui.horizontal(|ui| {
-                    let pin_label = if self.config.always_on_top {
-                        RichText::new("📌").color(self.gold_glow())
-                    } else {
-                        RichText::new("📌").color(self.arcane_purple())
-                    };
+            assert!(bot.fish_once().unwrap());
+            assert_eq!(
+                *actions.lock().unwrap(),
+                vec![InputAction::ClickAt(300, 400), InputAction::Click]
+            );
+            assert_eq!(bot.get_state().current_phase, FishingPhase::Caught);
+        }
 
-                    ui.vertical(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.heading(
-                                RichText::new("Arcane Fishing Automaton")
-                                    .size(self.scaled_font_size(26.0))
-                                    .strong()
-                                    .color(self.gold_glow()),
-                            );
-                            ui.add_space(8.0);
-                            ui.label(
-                                RichText::new("RUST EDITION")
-                                    .background_color(Color32::from_rgba_unmultiplied(
-                                        60, 40, 100, 200,
-                                    ))
-                                    .color(self.gold_glow())
-                                    .strong()
-                                    .size(self.scaled_font_size(12.0)),
-                            );
-                        });
-                        ui.label(
-                            RichText::new("Dark Tides, Bright Runes")
-                                .color(self.arcane_blue())
-                                .size(self.scaled_font_size(13.0)),
-                        );
-                    });
+        #[test]
+        fn fish_once_re_equips_a_missing_rod_before_giving_up() {
+            let config = BotConfig {
+                rod_check_enabled: true,
+                ..test_config()
+            };
+            let TestBot {
+                bot,
+                clock,
+                actions,
+            } = recording_bot(config, bite_and_catch_frame());
+            bot.state.write().running = true;
+
+            assert!(bot.fish_once().is_err());
+            let equip = InputAction::Key('5', Duration::from_millis(50));
+            assert_eq!(*actions.lock().unwrap(), vec![equip.clone(), equip]);
+            assert_eq!(clock.sleeps(), vec![Duration::from_millis(500); 2]);
+        }
 
-                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                        let pin = ui
-                            .add(
-                                Button::new(pin_label)
-                                    .min_size(self.scaled_button_size(32.0, 32.0))
-                                    .fill(Color32::from_rgba_unmultiplied(40, 30, 70, 180)),
-                            )
-                            .on_hover_text("Toggle always on top");
+        #[test]
+        fn concurrent_starts_run_exactly_one_loop() {
+            let config = BotConfig {
+                notify_on_start: false,
+                notify_on_stop: false,
+                stop_at_session_goal: true,
+                session_fish_goal: 3,
+                cast_click_pos: Some((300, 400)),
+                ..test_config()
+            };
+            let TestBot { bot, actions, .. } = recording_bot(config, bite_and_catch_frame());
+            let events = bot.subscribe();
 
-                        if pin.clicked() {
-                            self.config.always_on_top = !self.config.always_on_top;
-                        }
-                    });
-                });
+            let started: Vec<bool> = thread::scope(|scope| {
+                let starts: Vec<_> = (0..8).map(|_| scope.spawn(|| bot.start())).collect();
+                starts.into_iter().map(|s| s.join().unwrap()).collect()
             });
+            assert_eq!(started.iter().filter(|&&s| s).count(), 1);
+
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while bot.loop_active.load(Ordering::Acquire) {
+                assert!(Instant::now() < deadline, "run loop never finished");
+                thread::sleep(Duration::from_millis(10));
+            }
+
+            // A second loop would have pushed the count past the goal or stopped twice
+            let events: Vec<BotEvent> = events.try_iter().collect();
+            let catches: Vec<u64> = events
+                .iter()
+                .filter_map(|event| match event {
+                    BotEvent::Caught { fish_count } => Some(*fish_count),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(catches, vec![1, 2, 3]);
+            let stops = events
+                .iter()
+                .filter(|event| matches!(event, BotEvent::Stopped { .. }))
+                .count();
+            assert_eq!(stops, 1);
+
+            // Every cast went through the injected backend, which the stop released
+            let actions = actions.lock().unwrap();
+            let casts = actions
+                .iter()
+                .filter(|action| **action == InputAction::ClickAt(300, 400))
+                .count();
+            assert_eq!(casts, 3);
+            assert_eq!(actions.last(), Some(&InputAction::ReleaseAll));
         }
 
-        #[cfg(target_os = "macos")]
-        fn render_macos_toolbar(&mut self, ui: &mut Ui) {
-            ui.horizontal(|ui| {
-                if ui.button("\u{25C0}").clicked() {}
-                if ui.button("\u{25B6}").clicked() {}
-                if ui.button("\u{21BA}").clicked() {}
-                ui.add_space(10.0 * self.scale_factor);
-                let edit = egui::TextEdit::singleline(&mut self.safari_url)
-                    .hint_text("Address")
-                    .desired_width(f32::INFINITY);
-                ui.add(edit);
-            });
-            ui.separator();
+        /// Gaps between the feeds `schedule_feed` plans over `rounds` feeds.
+        fn feed_gaps(config: BotConfig, rounds: usize) -> Vec<u64> {
+            let TestBot { bot, .. } = recording_bot(config, bite_and_catch_frame());
+            let mut at = 0;
+            (0..rounds)
+                .map(|_| {
+                    bot.schedule_feed(at);
+                    let next = bot.get_state().next_feed_at_fish.unwrap();
+                    let gap = next - at;
+                    at = next;
+                    gap
+                })
+                .collect()
         }
 
-        fn render_bottom_buttons(&mut self, ui: &mut Ui) {
-            self.aura_frame(self.panel_fill()).show(ui, |ui| {
-                ui.horizontal_centered(|ui| {
-                    let size = self.scaled_button_size(52.0, 52.0);
+        #[test]
+        fn feed_gaps_stay_within_the_jitter() {
+            let config = BotConfig {
+                fish_per_feed: 10,
+                feed_jitter: 3,
+                rng_seed: 42,
+                ..test_config()
+            };
+            let gaps = feed_gaps(config.clone(), 200);
 
-                    if ui
-                        .add(
-                            Button::new(
-                                RichText::new("⚙️")
-                                    .size(self.scaled_font_size(20.0))
-                                    .color(self.gold_glow()),
-                            )
-                            .min_size(size)
-                            .fill(Color32::from_rgba_unmultiplied(40, 30, 70, 200)),
-                        )
-                        .clicked()
-                    {
-                        self.show_settings = !self.show_settings;
-                    }
+            assert!(gaps.iter().all(|gap| (7..=13).contains(gap)), "{:?}", gaps);
+            assert!(gaps.iter().any(|&gap| gap != 10), "jitter never applied");
+            // The same seed plans the same feeds
+            assert_eq!(feed_gaps(config, 200), gaps);
+        }
 
-                    if ui
-                        .add(
-                            Button::new(
-                                RichText::new("📊")
-                                    .size(self.scaled_font_size(20.0))
-                                    .color(self.arcane_blue()),
-                            )
-                            .min_size(size)
-                            .fill(Color32::from_rgba_unmultiplied(40, 30, 70, 200)),
-                        )
-                        .clicked()
-                    {
-                        self.show_advanced_stats = !self.show_advanced_stats;
-                    }
-                });
-            });
+        #[test]
+        fn feed_jitter_never_schedules_a_zero_gap() {
+            let config = BotConfig {
+                fish_per_feed: 2,
+                feed_jitter: 5,
+                rng_seed: 42,
+                ..test_config()
+            };
+            let gaps = feed_gaps(config, 100);
+            assert!(gaps.iter().all(|gap| (1..=3).contains(gap)), "{:?}", gaps);
         }
-        pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-            // Enhanced styling for dark fantasy aesthetic
-            let mut style = (*cc.egui_ctx.style()).clone();
-            style.spacing.item_spacing = vec2(10.0, 8.0);
-            style.spacing.window_margin = egui::style::Margin::same(18.0);
-            style.spacing.button_padding = vec2(14.0, 10.0);
-            style.spacing.indent = 22.0;
 
-            style.visuals = Visuals::dark();
-            style.visuals.override_text_color = Some(Color32::from_rgb(215, 225, 255));
-            style.visuals.window_fill = Self::night_sky();
-            style.visuals.panel_fill = Color32::from_rgb(16, 18, 34);
-            style.visuals.widgets.inactive.bg_fill = Color32::from_rgb(28, 32, 54);
-            style.visuals.widgets.hovered.bg_fill = Color32::from_rgb(60, 80, 130);
-            style.visuals.widgets.active.bg_fill = Color32::from_rgb(90, 110, 170);
-            style.visuals.selection.bg_fill = Color32::from_rgb(190, 140, 70);
-            style.visuals.widgets.noninteractive.fg_stroke.color = Color32::from_rgb(215, 225, 255);
+        #[test]
+        fn a_dark_bite_region_alone_does_not_count_as_a_blank_screen() {
+            let config = BotConfig {
+                blank_frame_pause_enabled: true,
+                ..test_config()
+            };
+            let frame = RgbaImage::from_fn(60, 20, |x, _| {
+                if x < 20 {
+                    image::Rgba([0, 0, 0, 255])
+                } else {
+                    BACKGROUND
+                }
+            });
+            let TestBot { bot, clock, .. } = recording_bot(config, frame);
+            bot.state.write().running = true;
 
-            cc.egui_ctx.set_style(style);
+            assert!(!bot.wait_while_screen_blank());
+            assert!(clock.sleeps().is_empty());
+        }
 
-            // Load configuration and statistics
-            let config = BotConfig::load().unwrap_or_default();
-            let lifetime_stats = LifetimeStats::load().unwrap_or_default();
+        #[test]
+        fn catch_rate_guard_pauses_on_missed_casts_not_errors() {
+            let config = BotConfig {
+                low_success_pause_enabled: true,
+                low_success_rate_floor: 40.0,
+                low_success_window: 10,
+                notify_on_error: false,
+                ..test_config()
+            };
+            let TestBot { bot, .. } = recording_bot(config, bite_and_catch_frame());
+            bot.state.write().running = true;
 
-            // Initialize resolution presets
-            let mut presets = HashMap::new();
-            presets.insert(
-                "3440x1440".to_string(),
-                (
-                    "3440x1440 Ultrawide".to_string(),
-                    Region {
-                        x: 1321,
-                        y: 99,
-                        width: 768,
-                        height: 546,
-                    },
-                    Region {
-                        x: 3097,
-                        y: 1234,
-                        width: 342,
-                        height: 205,
-                    },
-                    Region {
-                        x: 274,
-                        y: 1301,
-                        width: 43,
-                        height: 36,
-                    },
-                ),
+            // Casts that came back empty without an error still count as misses
+            for caught in [true, false, false, true, false, false, true, false, false] {
+                bot.performance_monitor.lock().unwrap().record_cast(caught);
+                bot.check_success_rate_guard();
+            }
+            assert!(!bot.get_state().paused, "fewer casts than the window");
+
+            bot.performance_monitor.lock().unwrap().record_cast(false);
+            bot.check_success_rate_guard();
+            assert!(bot.get_state().paused);
+            assert_eq!(
+                bot.performance_monitor
+                    .lock()
+                    .unwrap()
+                    .get_rolling_catch_rate(10),
+                None
             );
-            presets.insert(
-                "1920x1080".to_string(),
-                (
-                    "1920x1080 Standard".to_string(),
-                    Region {
-                        x: 598,
-                        y: 29,
-                        width: 901,
-                        height: 477,
-                    },
-                    Region {
-                        x: 1649,
-                        y: 632,
-                        width: 270,
-                        height: 447,
-                    },
-                    Region {
-                        x: 212,
-                        y: 984,
-                        width: 21,
-                        height: 18,
-                    },
-                ),
+        }
+
+        #[test]
+        fn timed_feeding_counts_each_feed() {
+            let config = BotConfig {
+                safety_feed_cooldown_secs: 300,
+                feed_sequence: vec![config::InputStep::Tap('3')],
+                ..test_config()
+            };
+            let TestBot {
+                bot,
+                clock,
+                actions,
+            } = recording_bot(config, bite_and_catch_frame());
+            bot.state.write().start_time = Some(clock.now());
+            let feeds_before = bot.get_lifetime_stats().total_feeds;
+
+            bot.feed_if_timer_due();
+            assert_eq!(
+                bot.get_state().session_feeds,
+                0,
+                "fed before the first interval"
             );
 
-            Self {
-                bot: AdvancedFishingBot::new(config.clone(), lifetime_stats),
-                config,
-                show_settings: false,
-                show_advanced_stats: false,
-                status_messages: vec![],
-                last_update: Instant::now(),
-                last_status: String::new(),
-                resolution_presets: presets,
-                window_size: egui::Vec2::new(900.0, 800.0),
-                scale_factor: 1.0,
-                #[cfg(target_os = "macos")]
-                safari_url: String::new(),
+            for _ in 0..2 {
+                clock.advance(Duration::from_secs(300));
+                bot.feed_if_timer_due();
+                bot.feed_if_timer_due();
             }
+
+            assert_eq!(bot.get_state().session_feeds, 2);
+            assert_eq!(bot.get_lifetime_stats().total_feeds, feeds_before + 2);
+            let tap = InputAction::Key('3', Duration::from_millis(50));
+            assert_eq!(*actions.lock().unwrap(), vec![tap.clone(), tap]);
         }
 
-        fn update_status(&mut self, message: String) {
-            let now = Local::now();
-            let timestamped_message = format!(
-                "[{:02}:{:02}:{:02}] {}",
-                now.hour(),
-                now.minute(),
-                now.second(),
-                message
-            );
-            self.status_messages.push((now, timestamped_message));
+        #[test]
+        fn resumed_session_counts_on_from_the_checkpoint() {
+            let config = BotConfig {
+                notify_on_start: false,
+                notify_on_stop: false,
+                stop_at_session_goal: true,
+                session_fish_goal: 12,
+                cast_click_pos: Some((300, 400)),
+                ..test_config()
+            };
+            let TestBot { bot, actions, .. } = recording_bot(config, bite_and_catch_frame());
+            let events = bot.subscribe();
+            let checkpoint = SessionCheckpoint {
+                saved_at: String::new(),
+                fish: 10,
+                feeds: 2,
+                runtime_seconds: 600,
+                best_streak: 7,
+                current_streak: 3,
+                errors: 1,
+            };
 
-            if self.status_messages.len() > 100 {
-                self.status_messages.remove(0);
+            assert!(bot.resume_session(&checkpoint));
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while bot.loop_active.load(Ordering::Acquire) {
+                assert!(Instant::now() < deadline, "run loop never finished");
+                thread::sleep(Duration::from_millis(10));
             }
+
+            // The loop's first catch already builds on the checkpoint's count
+            let catches: Vec<u64> = events
+                .try_iter()
+                .filter_map(|event| match event {
+                    BotEvent::Caught { fish_count } => Some(fish_count),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(catches, vec![11, 12]);
+            let state = bot.get_state();
+            assert_eq!(state.session_feeds, 2);
+            assert!(state.session_best_streak >= 7);
+            assert!(state.next_feed_at_fish.is_some_and(|at| at > 10));
+            let casts = actions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|action| **action == InputAction::ClickAt(300, 400))
+                .count();
+            assert_eq!(casts, 2);
         }
-    }
 
-    impl eframe::App for AdvancedFishingBotApp {
-        fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
-            // Update window size and scale factor
-            let current_size = ctx.screen_rect().size();
-            if (current_size - self.window_size).length() > 10.0 {
-                self.window_size = current_size;
-                self.scale_factor = (current_size.x / 900.0)
-                    .min(current_size.y / 800.0)
-                    .clamp(0.5, 2.0);
+        #[test]
+        fn failsafe_stop_keeps_its_phase_and_status() {
+            let config = BotConfig {
+                notify_on_error: false,
+                notify_on_stop: false,
+                ..test_config()
+            };
+            let TestBot { bot, clock, .. } = recording_bot(config, bite_and_catch_frame());
+            {
+                let mut state = bot.state.write();
+                state.running = true;
+                state.start_time = Some(clock.now());
+                state.enter_phase(FishingPhase::Reeling, clock.now());
             }
+            clock.advance(Duration::from_secs(3));
+
+            bot.handle_failsafe();
+
+            let state = bot.get_state();
+            assert!(!state.running);
+            assert_eq!(state.current_phase, FishingPhase::Failsafe);
+            assert_eq!(state.phase_started_at, None);
+            assert_eq!(state.status, bot.present(t("status.failsafe")));
+            assert_eq!(
+                state.phase_durations.get(&FishingPhase::Reeling),
+                Some(&Duration::from_secs(3))
+            );
+        }
 
-            // Window properties
-            if self.config.always_on_top {
-                ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
-                    egui::WindowLevel::AlwaysOnTop,
-                ));
-            }
+        #[test]
+        fn error_backoff_grows_per_consecutive_error_and_caps_at_five_seconds() {
+            let config = BotConfig {
+                notify_on_error: false,
+                ..test_config()
+            };
+            let TestBot { bot, clock, .. } = recording_bot(config, bite_and_catch_frame());
 
-            // Update status from bot
-            if self.last_update.elapsed() > Duration::from_millis(100) {
-                let state = self.bot.get_state();
-                if !state.status.is_empty() && state.status != self.last_status {
-                    self.update_status(state.status.clone());
-                    self.last_status = state.status;
-                }
-                self.last_update = Instant::now();
+            let error = anyhow!("capture failed");
+            for consecutive in 1..=7 {
+                bot.handle_error(&error, consecutive);
             }
 
-            CentralPanel::default().show(ctx, |ui| {
-                ScrollArea::vertical()
-                    .auto_shrink([true, true])
-                    .show(ui, |ui| {
-                        // Set dynamic spacing based on scale
-                        ui.spacing_mut().item_spacing =
-                            egui::vec2(8.0 * self.scale_factor, 6.0 * self.scale_factor);
-                        ui.spacing_mut().button_padding =
-                            egui::vec2(8.0 * self.scale_factor, 6.0 * self.scale_factor);
+            let expected: Vec<_> = [1, 2, 3, 4, 5, 5, 5]
+                .into_iter()
+                .map(Duration::from_secs)
+                .collect();
+            assert_eq!(clock.sleeps(), expected);
+            assert_eq!(bot.get_state().errors_count, 7);
+        }
 
-                        // Enhanced Header
-                        self.render_header(ui);
-                        self.add_scaled_space(ui, 8.0);
+        #[test]
+        fn anti_afk_nudges_once_the_jittered_idle_deadline_passes() {
+            let config = BotConfig {
+                anti_afk_enabled: true,
+                anti_afk_idle_secs: 100,
+                rng_seed: 7,
+                ..test_config()
+            };
+            let TestBot {
+                bot,
+                clock,
+                actions,
+            } = recording_bot(config, bite_and_catch_frame());
+
+            // The first check only schedules, within ±15% of the idle time
+            bot.check_anti_afk();
+            let deadline = bot.get_state().next_anti_afk_at.unwrap();
+            let until = deadline - clock.now();
+            assert!((85..=115).contains(&until.as_secs()), "{:?}", until);
+
+            clock.advance(Duration::from_secs(84));
+            bot.check_anti_afk();
+            assert!(actions.lock().unwrap().is_empty());
+
+            clock.advance(Duration::from_secs(32));
+            bot.check_anti_afk();
+            let actions = actions.lock().unwrap().clone();
+            assert!(
+                matches!(
+                    actions.as_slice(),
+                    [InputAction::Key('w', forward), InputAction::Key('s', back)]
+                        if forward == back
+                            && (30..=90).contains(&forward.as_millis())
+                ),
+                "{:?}",
+                actions
+            );
+            assert_eq!(clock.sleeps(), vec![Duration::from_millis(100)]);
+            assert!(bot.get_state().next_anti_afk_at.unwrap() > deadline);
+        }
+    }
+}
 
-                        // Enhanced Control Panel
-                        self.render_control_panel(ui);
-                        self.add_scaled_space(ui, 12.0);
+// ===== STATUS SERVER MODULE =====
+mod server {
+    use super::*;
+    use bot::{AdvancedFishingBot, BotEvent, FishingPhase, StopReason};
+    use config::LifetimeStats;
+    use futures_util::{SinkExt, StreamExt};
+    use std::collections::VecDeque;
+    use tiny_http::{Header, Method, Request, Response, Server};
+    use tokio::sync::broadcast;
+    use tokio_tungstenite::tungstenite::handshake::server::{
+        ErrorResponse, Request as WsRequest, Response as WsResponse,
+    };
+    use tokio_tungstenite::tungstenite::{http::StatusCode, Message};
 
-                        // Status & Progress
-                        self.render_status_panel(ui);
-                        self.add_scaled_space(ui, 12.0);
+    /// Events kept for `/events` pollers that fall behind.
+    const EVENT_BACKLOG: usize = 500;
 
-                        // Enhanced Statistics Panel
-                        self.render_statistics_panel(ui);
-                        self.add_scaled_space(ui, 12.0);
+    #[derive(Serialize)]
+    struct NumberedEvent {
+        seq: u64,
+        #[serde(flatten)]
+        event: BotEvent,
+    }
 
-                        // Performance Monitor
-                        self.render_performance_panel(ui);
-                        self.add_scaled_space(ui, 12.0);
+    #[derive(Default)]
+    struct EventLog {
+        next_seq: u64,
+        events: VecDeque<NumberedEvent>,
+    }
 
-                        // Activity Monitor
-                        self.render_activity_monitor(ui);
-                        self.add_scaled_space(ui, 12.0);
+    #[derive(Serialize)]
+    struct EventsPage<'a> {
+        /// Pass back as `since` to get only newer events.
+        next_seq: u64,
+        events: Vec<&'a NumberedEvent>,
+    }
 
-                        // Settings Panel - Now responsive
-                        self.render_bottom_buttons(ui);
-                    });
-            });
+    /// What `/ws` clients receive: each event is followed by the status it led to.
+    #[derive(Serialize)]
+    #[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+    enum PushMessage<'a> {
+        Event(&'a BotEvent),
+        Status(&'a StatusSnapshot),
+    }
 
-            // Settings Window
-            if self.show_settings {
-                self.render_settings_window(ctx);
-            }
+    #[derive(Serialize)]
+    struct StatusSnapshot {
+        running: bool,
+        paused: bool,
+        status: String,
+        phase: FishingPhase,
+        fish_count: u64,
+        last_hunger: Option<u32>,
+        errors_count: u32,
+        uptime_percentage: Option<f32>,
+        fish_per_hour: f32,
+        success_rate: f32,
+        bite_match_pixels: u32,
+        catch_match_pixels: u32,
+        session_best_streak: u32,
+        current_streak: u32,
+        session_seconds: Option<u64>,
+        lifetime: LifetimeStats,
+    }
 
-            // Advanced Statistics Window
-            if self.show_advanced_stats {
-                self.render_advanced_stats_window(ctx);
+    impl StatusSnapshot {
+        fn capture(bot: &AdvancedFishingBot) -> Self {
+            let state = bot.get_state();
+            let (success_rate, _, _) = bot.get_performance_stats();
+            Self {
+                running: state.running,
+                paused: state.paused,
+                status: state.status,
+                phase: state.current_phase,
+                fish_count: state.fish_count,
+                last_hunger: state.last_hunger,
+                errors_count: state.errors_count,
+                uptime_percentage: state.uptime_percentage,
+                fish_per_hour: state.fish_per_hour,
+                success_rate,
+                bite_match_pixels: state.bite_match_pixels,
+                catch_match_pixels: state.catch_match_pixels,
+                session_best_streak: state.session_best_streak,
+                current_streak: state.current_streak,
+                session_seconds: state.start_time.map(|start| start.elapsed().as_secs()),
+                lifetime: bot.get_lifetime_stats(),
             }
-
-            ctx.request_repaint_after(Duration::from_millis(100));
-        }
-
-        fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
-            self.bot.stop();
-            self.config.save().ok();
         }
     }
 
-    impl AdvancedFishingBotApp {
-        fn render_control_panel(&mut self, ui: &mut Ui) {
-            self.aura_frame(self.panel_fill()).show(ui, |ui| {
-                let state = self.bot.get_state();
-                ui.heading(
-                    RichText::new("Arcane Controls")
-                        .size(self.scaled_font_size(18.0))
-                        .color(self.arcane_blue()),
-                );
-                ui.add_space(6.0 * self.scale_factor);
-
-                ui.horizontal(|ui| {
-                    let button_size = self.scaled_button_size(140.0, 54.0);
+    /// Serves bot status on `/status`, Prometheus metrics on `/metrics`, bot
+    /// events on `/events?since=<seq>` and, when `token` is set, the `/start`,
+    /// `/stop` and `/pause` control endpoints until the process exits.
+    pub fn spawn(port: u16, token: String, bot: AdvancedFishingBot) {
+        let event_log = Arc::new(Mutex::new(EventLog::default()));
+        let receiver = bot.subscribe();
+        let log = event_log.clone();
+        thread::spawn(move || {
+            for event in receiver {
+                let Ok(mut log) = log.lock() else { break };
+                let seq = log.next_seq;
+                log.next_seq += 1;
+                log.events.push_back(NumberedEvent { seq, event });
+                if log.events.len() > EVENT_BACKLOG {
+                    log.events.pop_front();
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            let server = match Server::http(("0.0.0.0", port)) {
+                Ok(server) => server,
+                Err(e) => {
+                    log::error!("Failed to start status server on port {}: {}", port, e);
+                    return;
+                }
+            };
 
-                    let start_enabled = !state.running;
-                    let start_button = Button::new(
-                        RichText::new("▶ Start")
-                            .size(self.scaled_font_size(16.0))
-                            .strong()
-                            .color(Color32::BLACK),
-                    )
-                    .min_size(button_size)
-                    .fill(if start_enabled {
-                        self.emerald()
-                    } else {
-                        Color32::from_rgba_unmultiplied(40, 60, 50, 140)
-                    });
+            for request in server.incoming_requests() {
+                handle_request(request, &token, &bot, &event_log);
+            }
+        });
+    }
 
-                    if ui.add_enabled(start_enabled, start_button).clicked() {
-                        self.bot.start();
+    /// Pushes every bot event, followed by a fresh status snapshot, to WebSocket
+    /// clients connected to `/ws` on `port`. Clients get a snapshot on connect.
+    pub fn spawn_websocket(port: u16, bot: AdvancedFishingBot) {
+        let bot = Arc::new(bot);
+        let (updates, _) = broadcast::channel::<String>(256);
+
+        let receiver = bot.subscribe();
+        let forward_bot = bot.clone();
+        let forward = updates.clone();
+        thread::spawn(move || {
+            for event in receiver {
+                let snapshot = StatusSnapshot::capture(&forward_bot);
+                for message in [PushMessage::Event(&event), PushMessage::Status(&snapshot)] {
+                    if let Ok(text) = serde_json::to_string(&message) {
+                        // No connected clients is not an error
+                        let _ = forward.send(text);
+                    }
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            let rt = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    log::error!("Failed to start WebSocket runtime: {}", e);
+                    return;
+                }
+            };
+            rt.block_on(async move {
+                let listener = match tokio::net::TcpListener::bind(("0.0.0.0", port)).await {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        log::error!("Failed to start WebSocket server on port {}: {}", port, e);
+                        return;
                     }
+                };
 
-                    let pause_label = if state.paused {
-                        "▶ Resume"
-                    } else {
-                        "⏸ Pause"
-                    };
-                    let pause_color = if state.paused {
-                        self.emerald()
-                    } else {
-                        Color32::from_rgb(200, 170, 60)
+                loop {
+                    let stream = match listener.accept().await {
+                        Ok((stream, _)) => stream,
+                        Err(e) => {
+                            log::warn!("WebSocket accept failed: {}", e);
+                            continue;
+                        }
                     };
+                    let snapshot = StatusSnapshot::capture(&bot);
+                    let hello =
+                        serde_json::to_string(&PushMessage::Status(&snapshot)).unwrap_or_default();
+                    tokio::spawn(serve_websocket(stream, updates.subscribe(), hello));
+                }
+            });
+        });
+    }
 
-                    if ui
-                        .add_enabled(
-                            state.running,
-                            Button::new(
-                                RichText::new(pause_label)
-                                    .size(self.scaled_font_size(16.0))
-                                    .strong(),
-                            )
-                            .min_size(button_size)
-                            .fill(pause_color),
-                        )
-                        .clicked()
-                    {
-                        self.bot.pause();
-                    }
+    async fn serve_websocket(
+        stream: tokio::net::TcpStream,
+        mut updates: broadcast::Receiver<String>,
+        hello: String,
+    ) {
+        // The error type is tungstenite's own
+        #[allow(clippy::result_large_err)]
+        let only_ws = |request: &WsRequest, response: WsResponse| {
+            if request.uri().path() == "/ws" {
+                Ok(response)
+            } else {
+                let mut not_found = ErrorResponse::new(Some("Not Found".to_string()));
+                *not_found.status_mut() = StatusCode::NOT_FOUND;
+                Err(not_found)
+            }
+        };
+        let websocket = match tokio_tungstenite::accept_hdr_async(stream, only_ws).await {
+            Ok(websocket) => websocket,
+            Err(e) => {
+                log::debug!("WebSocket handshake failed: {}", e);
+                return;
+            }
+        };
+        let (mut outgoing, mut incoming) = websocket.split();
 
-                    if ui
-                        .add_enabled(
-                            state.running,
-                            Button::new(
-                                RichText::new("⏹ Stop")
-                                    .size(self.scaled_font_size(16.0))
-                                    .strong(),
-                            )
-                            .min_size(button_size)
-                            .fill(self.ember_red()),
-                        )
-                        .clicked()
-                    {
-                        self.bot.stop();
+        if outgoing.send(Message::Text(hello)).await.is_err() {
+            return;
+        }
+        loop {
+            tokio::select! {
+                update = updates.recv() => match update {
+                    Ok(text) => {
+                        if outgoing.send(Message::Text(text)).await.is_err() {
+                            break;
+                        }
                     }
-                });
-            });
+                    // A slow client misses the oldest updates rather than stalling others
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                // Client messages are ignored; the stream ending means it disconnected
+                message = incoming.next() => match message {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
         }
+    }
 
-        fn render_status_panel(&mut self, ui: &mut Ui) {
-            let state = self.bot.get_state();
-            self.aura_frame(Color32::from_rgb(22, 24, 46))
-                .show(ui, |ui| {
-                    let time = ui.ctx().input(|i| i.time);
-                    let glow = ((time as f32).sin() + 1.0) * 0.5;
-                    let icon_color = Color32::from_rgb(
-                        120,
-                        (80.0 + 60.0 * glow) as u8,
-                        (180.0 + 40.0 * glow) as u8,
-                    );
+    fn handle_request(
+        request: Request,
+        token: &str,
+        bot: &AdvancedFishingBot,
+        event_log: &Mutex<EventLog>,
+    ) {
+        let (path, query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+        let path = path.to_string();
+        let since = query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("since="))
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        let response = match (request.method(), path.as_str()) {
+            (Method::Get, "/status") => json_response(&StatusSnapshot::capture(bot)),
+            (Method::Get, "/events") => match event_log.lock() {
+                Ok(log) => json_response(&EventsPage {
+                    next_seq: log.next_seq,
+                    events: log.events.iter().filter(|e| e.seq >= since).collect(),
+                }),
+                Err(_) => Response::from_string("Event log unavailable").with_status_code(500),
+            },
+            (Method::Get, "/metrics") => metrics_response(bot),
+            (Method::Post, "/start" | "/stop" | "/pause") => {
+                if token.is_empty() {
+                    Response::from_string("Remote control disabled").with_status_code(403)
+                } else if !is_authorized(&request, token) {
+                    Response::from_string("Unauthorized").with_status_code(401)
+                } else {
+                    match path.as_str() {
+                        "/start" => {
+                            bot.start();
+                        }
+                        "/stop" => bot.stop(StopReason::UserRequested),
+                        _ => bot.pause(),
+                    }
+                    json_response(&StatusSnapshot::capture(bot))
+                }
+            }
+            _ => Response::from_string("Not Found").with_status_code(404),
+        };
 
-                    ui.horizontal(|ui| {
-                        ui.label(
-                            RichText::new("🪄")
-                                .size(self.scaled_font_size(28.0))
-                                .color(icon_color),
-                        );
+        let _ = request.respond(response);
+    }
 
-                        ui.vertical(|ui| {
-                            let phase_text = match state.current_phase {
-                                bot::FishingPhase::Idle => "Idle at the shoreline",
-                                bot::FishingPhase::Casting => "Casting enchanted line",
-                                bot::FishingPhase::WaitingForBite => "Waiting for a mystic bite",
-                                bot::FishingPhase::Reeling => "Reeling shimmering catch",
-                                bot::FishingPhase::Caught => "Catch secured!",
-                                bot::FishingPhase::Feeding => "Feeding the familiar",
-                                bot::FishingPhase::Error => "Disrupted by curses",
-                            };
+    /// Accepts either `Authorization: Bearer <token>` or the bare token.
+    fn is_authorized(request: &Request, token: &str) -> bool {
+        request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv("Authorization"))
+            .map(|header| {
+                let value = header.value.as_str().trim();
+                value.strip_prefix("Bearer ").unwrap_or(value).trim() == token
+            })
+            .unwrap_or(false)
+    }
 
-                            ui.label(
-                                RichText::new(phase_text)
-                                    .strong()
-                                    .size(self.scaled_font_size(17.0))
-                                    .color(self.gold_glow()),
-                            );
-                            ui.label(
-                                RichText::new(&state.status)
-                                    .color(self.arcane_blue())
-                                    .size(self.scaled_font_size(13.0)),
-                            );
-                        });
-                    });
+    /// Session and lifetime figures in the Prometheus text exposition format.
+    fn metrics_response(bot: &AdvancedFishingBot) -> Response<std::io::Cursor<Vec<u8>>> {
+        let state = bot.get_state();
+        let lifetime = bot.get_lifetime_stats();
+        let (success_rate, _, _) = bot.get_performance_stats();
+
+        let metrics: [(&str, &str, &str, f64); 6] = [
+            (
+                "fish_caught_total",
+                "counter",
+                "Fish caught across all sessions.",
+                lifetime.total_fish_caught as f64,
+            ),
+            (
+                "feeds_total",
+                "counter",
+                "Times the character was fed across all sessions.",
+                lifetime.total_feeds as f64,
+            ),
+            (
+                "errors_total",
+                "counter",
+                "Fishing errors across all sessions.",
+                lifetime.total_errors as f64,
+            ),
+            (
+                "fish_per_hour",
+                "gauge",
+                "Catch rate of the current session.",
+                state.fish_per_hour as f64,
+            ),
+            (
+                "success_rate",
+                "gauge",
+                "Share of recent fishing attempts that succeeded, 0 to 1.",
+                success_rate as f64 / 100.0,
+            ),
+            (
+                "session_running",
+                "gauge",
+                "1 while a session is running, otherwise 0.",
+                if state.running { 1.0 } else { 0.0 },
+            ),
+        ];
+
+        let mut body = String::new();
+        for (name, kind, help, value) in metrics {
+            body.push_str(&format!(
+                "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n"
+            ));
+        }
 
-                    ui.add_space(10.0 * self.scale_factor);
+        Response::from_string(body).with_header(
+            Header::from_bytes(&b"Content-Type"[..], &b"text/plain; version=0.0.4"[..]).unwrap(),
+        )
+    }
 
-                    let progress = self.phase_progress(&state.current_phase);
-                    let bar = egui::ProgressBar::new(progress)
-                        .desired_width(ui.available_width())
-                        .fill(Color32::from_rgb(60, 40, 90))
-                        .animate(true)
-                        .text("Runic flow");
-                    ui.add(bar);
-                });
+    fn json_response<T: Serialize>(value: &T) -> Response<std::io::Cursor<Vec<u8>>> {
+        match serde_json::to_string(value) {
+            Ok(body) => Response::from_string(body).with_header(
+                Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap(),
+            ),
+            Err(e) => Response::from_string(e.to_string()).with_status_code(500),
         }
+    }
+}
 
-        fn render_statistics_panel(&mut self, ui: &mut Ui) {
-            self.aura_frame(self.panel_fill()).show(ui, |ui| {
-                let state = self.bot.get_state();
-                let lifetime = self.bot.get_lifetime_stats();
+// ===== UI MODULE =====
+mod ui {
+    use super::*;
+    use bot::{AdvancedFishingBot, StopReason};
+    use config::{
+        BiteDetectionMode, BotConfig, InputStep, LifetimeStats, MoveStep, PopupDismiss, Region,
+        ScreenshotFormat, Spot, Theme, WindowsInputMethod,
+    };
+    use egui::*;
+    use egui_plot::{Bar, BarChart, Plot};
+    use i18n::{t, tf, Lang};
+
+    /// Setting filled in by the delayed cursor capture.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum CursorTarget {
+        Cast,
+        Reel,
+    }
 
-                ui.heading(
-                    RichText::new("Runic Metrics")
-                        .color(self.gold_glow())
-                        .size(self.scaled_font_size(18.0)),
-                );
-                ui.add_space(6.0 * self.scale_factor);
+    /// Time to move the mouse into place after pressing a capture button.
+    const CURSOR_CAPTURE_DELAY: Duration = Duration::from_secs(3);
 
-                ui.horizontal(|ui| {
-                    self.draw_gauge(
-                        ui,
-                        state.fish_per_hour,
-                        120.0,
-                        "Fish per hour",
-                        self.arcane_blue(),
-                    );
-                    self.draw_gauge(
-                        ui,
-                        state.uptime_percentage,
-                        100.0,
-                        "Uptime",
-                        self.gold_glow(),
-                    );
-                    self.draw_gauge(
-                        ui,
-                        lifetime.average_fish_per_hour,
-                        120.0,
-                        "Lifetime pace",
-                        self.arcane_purple(),
-                    );
-                });
+    /// Checkpoints older than this are not offered for resuming.
+    const RESUME_MAX_AGE_HOURS: i64 = 2;
 
-                ui.add_space(10.0 * self.scale_factor);
-                ui.horizontal(|ui| {
-                    self.render_digital_counter(
-                        ui,
-                        "Session Fish",
-                        &format!("{}", state.fish_count),
-                        self.arcane_blue(),
-                    );
-                    self.render_digital_counter(
-                        ui,
-                        "Best Streak",
-                        &format!("{}", state.session_best_streak),
-                        self.gold_glow(),
-                    );
-                    self.render_digital_counter(
-                        ui,
-                        "Total Fish",
-                        &format!("{}", lifetime.total_fish_caught),
-                        self.emerald(),
-                    );
-                    let runtime = if let Some(start) = state.start_time {
-                        let elapsed = start.elapsed();
-                        format!(
-                            "{:02}:{:02}:{:02}",
-                            elapsed.as_secs() / 3600,
-                            (elapsed.as_secs() % 3600) / 60,
-                            elapsed.as_secs() % 60
-                        )
-                    } else {
-                        "00:00:00".to_string()
-                    };
-                    self.render_digital_counter(ui, "Session Time", &runtime, self.arcane_purple());
-                });
-            });
+    /// Window size of the compact strip.
+    const COMPACT_SIZE: Vec2 = Vec2::new(460.0, 64.0);
+    const FULL_MIN_SIZE: Vec2 = Vec2::new(700.0, 600.0);
+
+    /// How often the region preview re-captures the screen.
+    const REGION_PREVIEW_REFRESH: Duration = Duration::from_millis(250);
+
+    /// Region row selected for arrow-key nudging in settings.
+    #[derive(Default)]
+    struct RegionNudge {
+        target: Option<Id>,
+        /// The selected region as drawn this frame; `None` while its row is hidden.
+        region: Option<Region>,
+        preview: Option<(Region, Instant, TextureHandle)>,
+        /// Why the last clipboard paste into the selected region was rejected.
+        paste_error: Option<String>,
+    }
+
+    /// Colors used by the UI helpers; one table per [`Theme`].
+    struct Palette {
+        dark_base: bool,
+        background: Color32,
+        panel: Color32,
+        inset: Color32,
+        button: Color32,
+        log_fill: Color32,
+        log_text: Color32,
+        text: Color32,
+        muted_text: Color32,
+        border: Color32,
+        gold: Color32,
+        blue: Color32,
+        purple: Color32,
+        emerald: Color32,
+        ember: Color32,
+        widget_inactive: Color32,
+        widget_hovered: Color32,
+        widget_active: Color32,
+        selection: Color32,
+    }
+
+    const ARCANE_DARK: Palette = Palette {
+        dark_base: true,
+        background: Color32::from_rgb(10, 12, 26),
+        panel: Color32::from_rgb(18, 20, 38),
+        inset: Color32::from_rgb(22, 24, 46),
+        button: Color32::from_rgba_premultiplied(31, 23, 55, 200),
+        log_fill: Color32::from_rgba_premultiplied(33, 28, 21, 220),
+        log_text: Color32::from_rgb(240, 225, 190),
+        text: Color32::from_rgb(215, 225, 255),
+        muted_text: Color32::from_rgb(200, 200, 220),
+        border: Color32::from_rgb(108, 86, 171),
+        gold: Color32::from_rgb(230, 180, 80),
+        blue: Color32::from_rgb(70, 130, 200),
+        purple: Color32::from_rgb(120, 80, 200),
+        emerald: Color32::from_rgb(70, 180, 130),
+        ember: Color32::from_rgb(200, 70, 70),
+        widget_inactive: Color32::from_rgb(28, 32, 54),
+        widget_hovered: Color32::from_rgb(60, 80, 130),
+        widget_active: Color32::from_rgb(90, 110, 170),
+        selection: Color32::from_rgb(190, 140, 70),
+    };
+
+    const LIGHT: Palette = Palette {
+        dark_base: false,
+        background: Color32::from_rgb(244, 243, 250),
+        panel: Color32::from_rgb(232, 232, 242),
+        inset: Color32::from_rgb(222, 222, 236),
+        button: Color32::from_rgb(214, 210, 235),
+        log_fill: Color32::from_rgb(248, 244, 232),
+        log_text: Color32::from_rgb(60, 50, 30),
+        text: Color32::from_rgb(25, 25, 40),
+        muted_text: Color32::from_rgb(80, 80, 100),
+        border: Color32::from_rgb(140, 120, 190),
+        gold: Color32::from_rgb(160, 100, 10),
+        blue: Color32::from_rgb(30, 90, 170),
+        purple: Color32::from_rgb(100, 60, 170),
+        emerald: Color32::from_rgb(20, 130, 80),
+        ember: Color32::from_rgb(180, 40, 40),
+        widget_inactive: Color32::from_rgb(220, 220, 232),
+        widget_hovered: Color32::from_rgb(200, 205, 230),
+        widget_active: Color32::from_rgb(180, 190, 225),
+        selection: Color32::from_rgb(235, 200, 130),
+    };
+
+    const HIGH_CONTRAST: Palette = Palette {
+        dark_base: true,
+        background: Color32::BLACK,
+        panel: Color32::BLACK,
+        inset: Color32::from_rgb(16, 16, 16),
+        button: Color32::from_rgb(40, 40, 40),
+        log_fill: Color32::BLACK,
+        log_text: Color32::WHITE,
+        text: Color32::WHITE,
+        muted_text: Color32::from_rgb(220, 220, 220),
+        border: Color32::WHITE,
+        gold: Color32::from_rgb(255, 215, 0),
+        blue: Color32::from_rgb(0, 200, 255),
+        purple: Color32::from_rgb(255, 130, 255),
+        emerald: Color32::from_rgb(0, 255, 130),
+        ember: Color32::from_rgb(255, 90, 90),
+        widget_inactive: Color32::from_rgb(30, 30, 30),
+        widget_hovered: Color32::from_rgb(70, 70, 70),
+        widget_active: Color32::from_rgb(110, 110, 110),
+        selection: Color32::from_rgb(0, 90, 200),
+    };
+
+    fn palette(theme: Theme) -> &'static Palette {
+        match theme {
+            Theme::ArcaneDark => &ARCANE_DARK,
+            Theme::Light => &LIGHT,
+            Theme::HighContrast => &HIGH_CONTRAST,
         }
+    }
 
-        fn draw_gauge(&self, ui: &mut Ui, value: f32, max: f32, label: &str, color: Color32) {
-            let size = 110.0 * self.scale_factor;
-            let (rect, _) = ui.allocate_exact_size(vec2(size, size), Sense::hover());
-            let painter = ui.painter_at(rect);
+    pub struct AdvancedFishingBotApp {
+        bot: AdvancedFishingBot,
+        config: BotConfig,
+        applied_theme: Theme,
+        show_settings: bool,
+        show_advanced_stats: bool,
+        status_messages: Vec<(chrono::DateTime<chrono::Local>, String)>,
+        last_update: Instant,
+        last_status: String,
+        resolution_presets: HashMap<String, (String, Region, Region, Region)>,
+        window_size: egui::Vec2,
+        scale_factor: f32,
+        /// Layout the viewport was last sized for, so resizes are sent only on change.
+        applied_compact: bool,
+        /// When the bot starts by itself after launch; `None` once started or cancelled.
+        auto_start_at: Option<Instant>,
+        confirm_reset: bool,
+        reset_clears_webhook: bool,
+        cursor_capture: Option<(Instant, CursorTarget)>,
+        region_nudge: RegionNudge,
+        /// Fastest fish/hr shown this session, keyed by the session's start time.
+        gauge_peak: (Option<Instant>, f32),
+        /// Unfinished session found at launch, until resumed or discarded.
+        resume_offer: Option<config::SessionCheckpoint>,
+        /// Name typed for saving the current regions as a preset.
+        new_preset_name: String,
+        /// Shown at launch when macOS has not granted Accessibility permission.
+        accessibility_prompt: bool,
+        #[cfg(target_os = "macos")]
+        safari_url: String,
+    }
 
-            let center = rect.center();
-            let radius = size * 0.45;
-            painter.circle_stroke(center, radius, Stroke::new(2.0, self.arcane_purple()));
+    impl AdvancedFishingBotApp {
+        fn add_scaled_space(&self, ui: &mut Ui, base_space: f32) {
+            ui.add_space(base_space * self.scale_factor);
+        }
 
-            let ratio = (value / max).clamp(0.0, 1.0);
-            let start = -PI * 0.75;
-            let sweep = PI * 1.5 * ratio;
-            let steps = 32;
-            let mut points = Vec::with_capacity(steps + 1);
-            for i in 0..=steps {
-                let t = start + sweep * (i as f32 / steps as f32);
-                points.push(center + vec2(t.cos() * radius, t.sin() * radius));
-            }
-            painter.add(epaint::PathShape {
-                points,
-                closed: false,
-                fill: Color32::TRANSPARENT,
-                stroke: Stroke::new(4.0, color),
-            });
+        fn scaled_font_size(&self, base_size: f32) -> f32 {
+            (base_size * self.scale_factor).clamp(8.0, 32.0)
+        }
 
-            let needle_angle = start + sweep;
-            let needle = center
-                + vec2(
-                    needle_angle.cos() * radius * 0.9,
-                    needle_angle.sin() * radius * 0.9,
-                );
-            painter.line_segment(
-                [center, needle],
-                Stroke::new(3.0, Color32::from_rgb(255, 240, 200)),
-            );
+        fn scaled_button_size(&self, base_width: f32, base_height: f32) -> egui::Vec2 {
+            egui::vec2(
+                (base_width * self.scale_factor).max(80.0),
+                (base_height * self.scale_factor).max(30.0),
+            )
+        }
 
-            painter.circle_filled(center, 6.0, self.gold_glow());
+        fn palette(&self) -> &'static Palette {
+            palette(self.config.theme)
+        }
 
-            let text = format!("{:.0}", value);
-            painter.text(
-                center,
-                Align2::CENTER_CENTER,
-                text,
-                FontId::proportional(self.scaled_font_size(14.0)),
-                Color32::from_rgb(230, 235, 255),
-            );
+        fn panel_fill(&self) -> Color32 {
+            self.palette().panel
+        }
 
-            let label_pos = egui::pos2(center.x, rect.bottom() + 4.0 * self.scale_factor);
-            painter.text(
-                label_pos,
-                Align2::CENTER_TOP,
-                label,
-                FontId::proportional(self.scaled_font_size(12.0)),
-                Color32::from_rgb(200, 200, 220),
-            );
+        fn inset_fill(&self) -> Color32 {
+            self.palette().inset
         }
 
-        fn render_digital_counter(&self, ui: &mut Ui, label: &str, value: &str, color: Color32) {
-            Frame::none()
-                .fill(Color32::from_rgba_unmultiplied(25, 20, 40, 180))
-                .stroke(self.rune_border())
-                .rounding(6.0)
-                .inner_margin(8.0 * self.scale_factor)
-                .show(ui, |ui| {
-                    ui.vertical(|ui| {
-                        ui.label(
-                            RichText::new(label)
-                                .size(self.scaled_font_size(12.0))
-                                .color(self.gold_glow()),
-                        );
-                        ui.label(
-                            RichText::new(value)
-                                .size(self.scaled_font_size(18.0))
-                                .color(color)
-                                .monospace()
-                                .strong(),
-                        );
-                    });
-                });
+        fn button_fill(&self) -> Color32 {
+            self.palette().button
         }
 
-        fn render_performance_panel(&mut self, ui: &mut Ui) {
-            self.aura_frame(self.panel_fill()).show(ui, |ui| {
-                ui.heading(
-                    RichText::new("⚡ Arcane Performance")
-                        .color(self.arcane_blue())
-                        .size(self.scaled_font_size(16.0)),
-                );
-                ui.separator();
+        fn rune_border(&self) -> Stroke {
+            Stroke {
+                width: 1.5,
+                color: self.palette().border,
+            }
+        }
 
-                let (success_rate, avg_time, error_count) = self.bot.get_performance_stats();
-                let state = self.bot.get_state();
+        fn gold_glow(&self) -> Color32 {
+            self.palette().gold
+        }
 
-                Grid::new("perf_grid")
-                    .num_columns(4)
-                    .spacing([20.0, 8.0])
-                    .show(ui, |ui| {
-                        ui.label(RichText::new("Success Rate:").strong());
-                        let success_color = if success_rate > 95.0 {
-                            self.emerald()
-                        } else if success_rate > 85.0 {
-                            self.gold_glow()
-                        } else {
-                            self.ember_red()
-                        };
-                        ui.label(
-                            RichText::new(format!("✅ {:.1}%", success_rate)).color(success_color),
-                        );
+        fn arcane_blue(&self) -> Color32 {
+            self.palette().blue
+        }
 
-                        ui.label(RichText::new("Uptime:").strong());
-                        let uptime_color = if state.uptime_percentage > 95.0 {
-                            self.emerald()
-                        } else {
-                            self.gold_glow()
-                        };
-                        ui.label(
-                            RichText::new(format!("📈 {:.1}%", state.uptime_percentage))
-                                .color(uptime_color),
-                        );
-                        ui.end_row();
+        fn arcane_purple(&self) -> Color32 {
+            self.palette().purple
+        }
 
-                        ui.label(RichText::new("Avg Operation:").strong());
-                        ui.label(
-                            RichText::new(format!("⏱️ {:.1}ms", avg_time.as_millis()))
-                                .color(self.arcane_blue()),
-                        );
+        fn emerald(&self) -> Color32 {
+            self.palette().emerald
+        }
 
-                        ui.label(RichText::new("Total Errors:").strong());
-                        let error_color = if error_count == 0 {
-                            self.emerald()
-                        } else if error_count < 5 {
-                            self.gold_glow()
-                        } else {
-                            self.ember_red()
-                        };
-                        ui.label(RichText::new(format!("❌ {}", error_count)).color(error_color));
-                        ui.end_row();
-                    });
-            });
+        fn ember_red(&self) -> Color32 {
+            self.palette().ember
         }
 
-        fn render_activity_monitor(&mut self, ui: &mut Ui) {
+        fn apply_theme(ctx: &Context, theme: Theme) {
+            let palette = palette(theme);
+            let mut style = (*ctx.style()).clone();
+            style.visuals = if palette.dark_base {
+                Visuals::dark()
+            } else {
+                Visuals::light()
+            };
+            style.visuals.override_text_color = Some(palette.text);
+            style.visuals.window_fill = palette.background;
+            style.visuals.panel_fill = palette.background;
+            style.visuals.widgets.inactive.bg_fill = palette.widget_inactive;
+            style.visuals.widgets.hovered.bg_fill = palette.widget_hovered;
+            style.visuals.widgets.active.bg_fill = palette.widget_active;
+            style.visuals.selection.bg_fill = palette.selection;
+            style.visuals.widgets.noninteractive.fg_stroke.color = palette.text;
+            ctx.set_style(style);
+        }
+
+        fn aura_frame(&self, fill: Color32) -> Frame {
             Frame::none()
-                .fill(Color32::from_rgba_unmultiplied(38, 32, 24, 220))
+                .fill(fill)
                 .stroke(self.rune_border())
                 .rounding(10.0)
-                .inner_margin(14.0 * self.scale_factor)
-                .show(ui, |ui| {
-                    ui.horizontal(|ui| {
-                        ui.heading(
-                            RichText::new("📜 Activity Log")
-                                .color(self.gold_glow())
-                                .size(self.scaled_font_size(16.0)),
-                        );
-                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                            if ui
-                                .button(
-                                    RichText::new("🧹")
-                                        .color(self.gold_glow())
-                                        .size(self.scaled_font_size(14.0)),
-                                )
-                                .clicked()
-                            {
-                                self.status_messages.clear();
-                            }
-                        });
-                    });
-                    ui.separator();
+                .inner_margin(12.0 * self.scale_factor)
+        }
 
-                    ScrollArea::vertical()
-                        .max_height(180.0 * self.scale_factor)
-                        .auto_shrink([false; 2])
-                        .show(ui, |ui| {
-                            for (_timestamp, message) in self.status_messages.iter().rev().take(25)
-                            {
-                                ui.label(
-                                    RichText::new(message)
-                                        .family(FontFamily::Proportional)
-                                        .color(Color32::from_rgb(240, 225, 190)),
-                                );
-                            }
-                        });
+        /// X/Y/W/H fields plus a toggle that selects the region for nudging:
+        /// arrows move it 1px (10px with Shift), Ctrl+arrows resize it.
+        fn region_editor(ui: &mut Ui, id: Id, region: &mut Region, nudge: &mut RegionNudge) {
+            ui.horizontal(|ui| {
+                ui.label("X");
+                ui.add(DragValue::new(&mut region.x));
+                ui.label("Y");
+                ui.add(DragValue::new(&mut region.y));
+                ui.label("W");
+                ui.add(DragValue::new(&mut region.width).clamp_range(1..=8000));
+                ui.label("H");
+                ui.add(DragValue::new(&mut region.height).clamp_range(1..=8000));
+                ui.label(t("settings.inset"));
+                ui.add(DragValue::new(&mut region.inset).clamp_range(0..=500))
+                    .on_hover_text(t("settings.inset_hint"));
+
+                if ui
+                    .small_button("📋")
+                    .on_hover_text(t("settings.copy_xywh_hint"))
+                    .clicked()
+                {
+                    ui.ctx().copy_text(region.to_xywh());
+                }
+
+                let selected = nudge.target == Some(id);
+                if ui
+                    .selectable_label(selected, "🎯")
+                    .on_hover_text(t("settings.nudge_hint"))
+                    .clicked()
+                {
+                    nudge.target = if selected { None } else { Some(id) };
+                    nudge.paste_error = None;
+                }
+            });
+
+            if nudge.target != Some(id) {
+                return;
+            }
+
+            if !ui.ctx().wants_keyboard_input() {
+                let (pasted, copy) = ui.input(|i| {
+                    let pasted = i.events.iter().find_map(|event| match event {
+                        Event::Paste(text) => Some(text.clone()),
+                        _ => None,
+                    });
+                    let copy = i.events.iter().any(|event| matches!(event, Event::Copy));
+                    (pasted, copy)
                 });
+
+                if let Some(text) = pasted {
+                    nudge.paste_error = region
+                        .apply_xywh(&text)
+                        .err()
+                        .map(|e| tf("settings.paste_rejected", &[("error", &e)]));
+                }
+                if copy {
+                    ui.ctx().copy_text(region.to_xywh());
+                }
+
+                let (dx, dy, resize) = ui.input(|i| {
+                    let step = if i.modifiers.shift { 10 } else { 1 };
+                    let mut dx = 0;
+                    let mut dy = 0;
+                    if i.key_pressed(Key::ArrowLeft) {
+                        dx -= step;
+                    }
+                    if i.key_pressed(Key::ArrowRight) {
+                        dx += step;
+                    }
+                    if i.key_pressed(Key::ArrowUp) {
+                        dy -= step;
+                    }
+                    if i.key_pressed(Key::ArrowDown) {
+                        dy += step;
+                    }
+                    (dx, dy, i.modifiers.ctrl)
+                });
+
+                if resize {
+                    region.width = (region.width as i32 + dx).clamp(1, 8000) as u32;
+                    region.height = (region.height as i32 + dy).clamp(1, 8000) as u32;
+                } else {
+                    region.x += dx;
+                    region.y += dy;
+                }
+            }
+            if let Some(error) = &nudge.paste_error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+            nudge.region = Some(*region);
+        }
+
+        fn popup_dismiss_editor(ui: &mut Ui, dismiss: &mut PopupDismiss) {
+            ui.horizontal(|ui| {
+                let is_click = matches!(dismiss, PopupDismiss::Click { .. });
+                if ui.radio(is_click, "Click").clicked() && !is_click {
+                    *dismiss = PopupDismiss::Click { x: 0, y: 0 };
+                }
+                if ui.radio(!is_click, "Key").clicked() && is_click {
+                    *dismiss = PopupDismiss::Key('e');
+                }
+
+                match dismiss {
+                    PopupDismiss::Click { x, y } => {
+                        ui.label("X");
+                        ui.add(DragValue::new(x));
+                        ui.label("Y");
+                        ui.add(DragValue::new(y));
+                    }
+                    PopupDismiss::Key(key) => Self::key_editor(ui, key),
+                }
+            });
+        }
+
+        /// Single-character field accepting one letter or digit.
+        fn key_editor(ui: &mut Ui, key: &mut char) {
+            let mut text = key.to_string();
+            ui.add(TextEdit::singleline(&mut text).desired_width(24.0));
+            if let Some(c) = text.chars().rev().find(|c| c.is_ascii_alphanumeric()) {
+                *key = c.to_ascii_lowercase();
+            }
+        }
+
+        /// Editable list of input steps with add, remove and restore-default controls.
+        fn sequence_editor(
+            ui: &mut Ui,
+            steps: &mut Vec<InputStep>,
+            default: fn() -> Vec<InputStep>,
+        ) {
+            let mut remove_step = None;
+            for (index, step) in steps.iter_mut().enumerate() {
+                ui.push_id(index, |ui| {
+                    ui.horizontal(|ui| {
+                        let kind = match step {
+                            InputStep::Click => t("settings.step_click"),
+                            InputStep::Tap(_) => t("settings.step_tap"),
+                            InputStep::Hold { .. } => t("settings.step_hold"),
+                            InputStep::Wait(_) => t("settings.step_wait"),
+                        };
+                        ComboBox::from_id_source("kind")
+                            .selected_text(kind)
+                            .width(70.0)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    step,
+                                    InputStep::Click,
+                                    t("settings.step_click"),
+                                );
+                                let is_tap = matches!(step, InputStep::Tap(_));
+                                if ui
+                                    .selectable_label(is_tap, t("settings.step_tap"))
+                                    .clicked()
+                                {
+                                    *step = InputStep::Tap('5');
+                                }
+                                let is_hold = matches!(step, InputStep::Hold { .. });
+                                if ui
+                                    .selectable_label(is_hold, t("settings.step_hold"))
+                                    .clicked()
+                                {
+                                    *step = InputStep::Hold { key: '5', ms: 1000 };
+                                }
+                                let is_wait = matches!(step, InputStep::Wait(_));
+                                if ui
+                                    .selectable_label(is_wait, t("settings.step_wait"))
+                                    .clicked()
+                                {
+                                    *step = InputStep::Wait(200);
+                                }
+                            });
+
+                        match step {
+                            InputStep::Click => {}
+                            InputStep::Tap(key) => Self::key_editor(ui, key),
+                            InputStep::Hold { key, ms } => {
+                                Self::key_editor(ui, key);
+                                ui.add(DragValue::new(ms).clamp_range(10..=10000).suffix(" ms"));
+                            }
+                            InputStep::Wait(ms) => {
+                                ui.add(DragValue::new(ms).clamp_range(0..=10000).suffix(" ms"));
+                            }
+                        }
+
+                        if ui.small_button("✖").clicked() {
+                            remove_step = Some(index);
+                        }
+                    });
+                });
+            }
+            if let Some(index) = remove_step {
+                steps.remove(index);
+            }
+
+            ui.horizontal(|ui| {
+                if ui.small_button(t("settings.add_step")).clicked() {
+                    steps.push(InputStep::Wait(200));
+                }
+                if ui.small_button(t("settings.default_steps")).clicked() {
+                    *steps = default();
+                }
+            });
+        }
+
+        /// Click intervals that repeat while reeling; empty falls back to the single interval.
+        fn reel_pattern_editor(ui: &mut Ui, pattern: &mut Vec<u64>) {
+            ui.horizontal_wrapped(|ui| {
+                let mut remove_interval = None;
+                for (index, ms) in pattern.iter_mut().enumerate() {
+                    ui.push_id(index, |ui| {
+                        ui.add(DragValue::new(ms).clamp_range(10..=1000).suffix(" ms"));
+                        if ui.small_button("✖").clicked() {
+                            remove_interval = Some(index);
+                        }
+                    });
+                }
+                if let Some(index) = remove_interval {
+                    pattern.remove(index);
+                }
+
+                if ui
+                    .small_button("➕")
+                    .on_hover_text(t("settings.add_interval"))
+                    .clicked()
+                {
+                    pattern.push(pattern.last().copied().unwrap_or(70));
+                }
+                if pattern.is_empty() {
+                    ui.label(t("settings.uses_click_interval"));
+                }
+            });
+        }
+
+        /// Checkbox plus X/Y fields for an optional screen position.
+        fn position_editor(ui: &mut Ui, label: &str, position: &mut Option<(i32, i32)>) {
+            let mut enabled = position.is_some();
+            ui.checkbox(&mut enabled, label);
+            match (enabled, position.as_mut()) {
+                (true, Some((x, y))) => {
+                    ui.label("X");
+                    ui.add(DragValue::new(x));
+                    ui.label("Y");
+                    ui.add(DragValue::new(y));
+                }
+                (true, None) => *position = Some((0, 0)),
+                (false, _) => *position = None,
+            }
+        }
+
+        /// Starts a delayed capture of the cursor position into `target`, showing a
+        /// countdown while one is pending.
+        fn cursor_capture_button(&mut self, ui: &mut Ui, target: CursorTarget) {
+            match self.cursor_capture {
+                Some((at, pending)) if pending == target => {
+                    let remaining = at.saturating_duration_since(Instant::now()).as_secs() + 1;
+                    ui.label(tf(
+                        "settings.capture_cursor_countdown",
+                        &[("secs", &remaining)],
+                    ));
+                }
+                _ => {
+                    if ui
+                        .button(t("settings.capture_cursor"))
+                        .on_hover_text(t("settings.capture_cursor_hint"))
+                        .clicked()
+                    {
+                        self.cursor_capture = Some((Instant::now() + CURSOR_CAPTURE_DELAY, target));
+                    }
+                }
+            }
+        }
+
+        fn finish_cursor_capture(&mut self) {
+            let Some((at, target)) = self.cursor_capture else {
+                return;
+            };
+            if Instant::now() < at {
+                return;
+            }
+            self.cursor_capture = None;
+
+            match input::cursor_position() {
+                Some((x, y)) => {
+                    match target {
+                        CursorTarget::Cast => self.config.cast_click_pos = Some((x, y)),
+                        CursorTarget::Reel => self.config.reel_click_pos = Some((x, y)),
+                    }
+                    self.update_status(tf("status.cursor_captured", &[("x", &x), ("y", &y)]));
+                }
+                None => self.update_status(t("status.cursor_capture_failed").to_string()),
+            }
+        }
+
+        /// Editor for one rotation spot; returns true when its remove button was clicked.
+        fn spot_editor(ui: &mut Ui, spot: &mut Spot, nudge: &mut RegionNudge) -> bool {
+            let mut remove = false;
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.spot_name"));
+                    ui.add(TextEdit::singleline(&mut spot.name).desired_width(160.0));
+                    if ui.button(t("settings.remove_spot")).clicked() {
+                        remove = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    Self::position_editor(ui, t("settings.cast_at"), &mut spot.cast_position);
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.red_region"));
+                    Self::region_editor(ui, ui.id().with("red"), &mut spot.red_region, nudge);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.yellow_region"));
+                    Self::region_editor(ui, ui.id().with("yellow"), &mut spot.yellow_region, nudge);
+                });
+
+                ui.label(t("settings.spot_movement"));
+                let mut remove_step = None;
+                for (index, step) in spot.movement.iter_mut().enumerate() {
+                    ui.push_id(index, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("settings.hold"));
+                            Self::key_editor(ui, &mut step.key);
+                            ui.add(
+                                DragValue::new(&mut step.hold_ms)
+                                    .clamp_range(10..=10000)
+                                    .suffix(" ms"),
+                            );
+                            if ui.small_button("✖").clicked() {
+                                remove_step = Some(index);
+                            }
+                        });
+                    });
+                }
+                if let Some(index) = remove_step {
+                    spot.movement.remove(index);
+                }
+                if ui.small_button(t("settings.add_step")).clicked() {
+                    spot.movement.push(MoveStep {
+                        key: 'w',
+                        hold_ms: 500,
+                    });
+                }
+            });
+            remove
+        }
+
+        fn phase_label(phase: &bot::FishingPhase) -> &'static str {
+            match phase {
+                bot::FishingPhase::Idle => t("phase.idle"),
+                bot::FishingPhase::Casting => t("phase.casting"),
+                bot::FishingPhase::WaitingForBite => t("phase.waiting"),
+                bot::FishingPhase::Reeling => t("phase.reeling"),
+                bot::FishingPhase::Caught => t("phase.caught"),
+                bot::FishingPhase::Feeding => t("phase.feeding"),
+                bot::FishingPhase::Error => t("phase.error"),
+                bot::FishingPhase::Failsafe => t("phase.failsafe"),
+            }
+        }
+
+        fn phase_progress(&self, phase: &bot::FishingPhase) -> f32 {
+            match phase {
+                bot::FishingPhase::Idle => 0.05,
+                bot::FishingPhase::Casting => 0.25,
+                bot::FishingPhase::WaitingForBite => 0.45,
+                bot::FishingPhase::Reeling => 0.7,
+                bot::FishingPhase::Caught => 1.0,
+                bot::FishingPhase::Feeding => 0.6,
+                bot::FishingPhase::Error => 0.0,
+                bot::FishingPhase::Failsafe => 0.0,
+            }
+        }
+
+        /// Resizes the viewport when compact mode is switched on or off.
+        fn apply_window_mode(&mut self, ctx: &Context) {
+            if self.applied_compact == self.config.compact_mode {
+                return;
+            }
+            self.applied_compact = self.config.compact_mode;
+
+            let (min_size, size) = if self.applied_compact {
+                (COMPACT_SIZE, COMPACT_SIZE)
+            } else {
+                (FULL_MIN_SIZE, self.window_size.max(FULL_MIN_SIZE))
+            };
+            ctx.send_viewport_cmd(ViewportCommand::MinInnerSize(min_size));
+            ctx.send_viewport_cmd(ViewportCommand::InnerSize(size));
+        }
+
+        fn render_compact_strip(&mut self, ctx: &Context) {
+            let state = self.bot.get_state();
+            CentralPanel::default().show(ctx, |ui| {
+                ui.horizontal_centered(|ui| {
+                    if state.running {
+                        if ui
+                            .add(Button::new(t("controls.stop")).fill(self.ember_red()))
+                            .clicked()
+                        {
+                            self.bot.stop(StopReason::UserRequested);
+                        }
+                    } else if ui
+                        .add(
+                            Button::new(RichText::new(t("controls.start")).color(Color32::BLACK))
+                                .fill(self.emerald()),
+                        )
+                        .clicked()
+                    {
+                        self.bot.start();
+                    }
+
+                    ui.label(
+                        RichText::new(Self::phase_label(&state.current_phase))
+                            .strong()
+                            .color(self.gold_glow()),
+                    );
+                    ui.label(tf("controls.compact_fish", &[("count", &state.fish_count)]));
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui
+                            .button("🗖")
+                            .on_hover_text(t("controls.expand_hint"))
+                            .clicked()
+                        {
+                            self.config.compact_mode = false;
+                        }
+                    });
+                });
+            });
+        }
+
+        fn render_warning_banner(&self, ui: &mut Ui, text: &str) {
+            Frame::none()
+                .fill(self.ember_red())
+                .rounding(10.0)
+                .inner_margin(8.0 * self.scale_factor)
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            RichText::new(text)
+                                .strong()
+                                .color(Color32::WHITE)
+                                .size(self.scaled_font_size(16.0)),
+                        );
+                    });
+                });
+        }
+
+        fn render_header(&mut self, ui: &mut Ui) {
+            #[cfg(target_os = "macos")]
+            {
+                self.render_macos_toolbar(ui);
+            }
+
+            self.aura_frame(self.panel_fill()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let pin_label = if self.config.always_on_top {
+                        RichText::new("📌").color(self.gold_glow())
+                    } else {
+                        RichText::new("📌").color(self.arcane_purple())
+                    };
+
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.heading(
+                                RichText::new("Arcane Fishing Automaton")
+                                    .size(self.scaled_font_size(26.0))
+                                    .strong()
+                                    .color(self.gold_glow()),
+                            );
+                            ui.add_space(8.0);
+                            ui.label(
+                                RichText::new("RUST EDITION")
+                                    .background_color(self.button_fill())
+                                    .color(self.gold_glow())
+                                    .strong()
+                                    .size(self.scaled_font_size(12.0)),
+                            );
+                        });
+                        ui.label(
+                            RichText::new("Dark Tides, Bright Runes")
+                                .color(self.arcane_blue())
+                                .size(self.scaled_font_size(13.0)),
+                        );
+                    });
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        let pin = ui
+                            .add(
+                                Button::new(pin_label)
+                                    .min_size(self.scaled_button_size(32.0, 32.0))
+                                    .fill(self.button_fill()),
+                            )
+                            .on_hover_text(t("controls.pin_hint"));
+
+                        if pin.clicked() {
+                            self.config.always_on_top = !self.config.always_on_top;
+                        }
+
+                        if ui
+                            .add(
+                                Button::new(RichText::new("🗕").color(self.arcane_purple()))
+                                    .min_size(self.scaled_button_size(32.0, 32.0))
+                                    .fill(self.button_fill()),
+                            )
+                            .on_hover_text(t("controls.compact_hint"))
+                            .clicked()
+                        {
+                            self.config.compact_mode = true;
+                        }
+
+                        let (failsafe_text, failsafe_color) = if self.bot.failsafe_armed() {
+                            (t("controls.failsafe_armed"), self.emerald())
+                        } else {
+                            (t("controls.failsafe_off"), self.ember_red())
+                        };
+                        ui.label(
+                            RichText::new(failsafe_text)
+                                .color(failsafe_color)
+                                .size(self.scaled_font_size(12.0)),
+                        )
+                        .on_hover_text(t("controls.failsafe_hint"));
+                    });
+                });
+            });
+        }
+
+        #[cfg(target_os = "macos")]
+        fn render_macos_toolbar(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                if ui.button("\u{25C0}").clicked() {}
+                if ui.button("\u{25B6}").clicked() {}
+                if ui.button("\u{21BA}").clicked() {}
+                ui.add_space(10.0 * self.scale_factor);
+                let edit = egui::TextEdit::singleline(&mut self.safari_url)
+                    .hint_text("Address")
+                    .desired_width(f32::INFINITY);
+                ui.add(edit);
+            });
+            ui.separator();
         }
 
-        fn render_settings_window(&mut self, ctx: &Context) {
-            Window::new("⚙️ Advanced Settings")
-                .default_size([700.0, 600.0])
-                .collapsible(false)
-                .show(ctx, |ui| {
-                    ScrollArea::vertical().show(ui, |ui| {
-                        // Basic Settings
-                        CollapsingHeader::new("🎯 Detection Settings")
-                            .default_open(true)
+        fn render_bottom_buttons(&mut self, ui: &mut Ui) {
+            self.aura_frame(self.panel_fill()).show(ui, |ui| {
+                ui.horizontal_centered(|ui| {
+                    let size = self.scaled_button_size(52.0, 52.0);
+
+                    if ui
+                        .add(
+                            Button::new(
+                                RichText::new("⚙️")
+                                    .size(self.scaled_font_size(20.0))
+                                    .color(self.gold_glow()),
+                            )
+                            .min_size(size)
+                            .fill(self.button_fill()),
+                        )
+                        .on_hover_text(t("controls.settings_hint"))
+                        .clicked()
+                    {
+                        self.show_settings = !self.show_settings;
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(
+                                RichText::new("📊")
+                                    .size(self.scaled_font_size(20.0))
+                                    .color(self.arcane_blue()),
+                            )
+                            .min_size(size)
+                            .fill(self.button_fill()),
+                        )
+                        .on_hover_text(t("controls.stats_hint"))
+                        .clicked()
+                    {
+                        self.show_advanced_stats = !self.show_advanced_stats;
+                    }
+                });
+            });
+        }
+        pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+            // Enhanced styling for dark fantasy aesthetic
+            let mut style = (*cc.egui_ctx.style()).clone();
+            style.spacing.item_spacing = vec2(10.0, 8.0);
+            style.spacing.window_margin = egui::style::Margin::same(18.0);
+            style.spacing.button_padding = vec2(14.0, 10.0);
+            style.spacing.indent = 22.0;
+            cc.egui_ctx.set_style(style);
+
+            // Load configuration and statistics
+            let config = BotConfig::load().unwrap_or_default();
+            Self::apply_theme(&cc.egui_ctx, config.theme);
+            let lifetime_stats = LifetimeStats::load().unwrap_or_default();
+
+            // Initialize resolution presets
+            let mut presets = HashMap::new();
+            presets.insert(
+                "3440x1440".to_string(),
+                (
+                    "3440x1440 Ultrawide".to_string(),
+                    Region::new(1321, 99, 768, 546),
+                    Region::new(3097, 1234, 342, 205),
+                    Region::new(274, 1301, 43, 36),
+                ),
+            );
+            presets.insert(
+                "1920x1080".to_string(),
+                (
+                    "1920x1080 Standard".to_string(),
+                    Region::new(598, 29, 901, 477),
+                    Region::new(1649, 632, 270, 447),
+                    Region::new(212, 984, 21, 18),
+                ),
+            );
+
+            let auto_start_at = config
+                .auto_start_on_launch
+                .then(|| Instant::now() + Duration::from_secs(config.auto_start_delay_secs as u64));
+            let accessibility_prompt = !config.input_dry_run && !input::accessibility_trusted();
+            ocr::init_tesseract(&config.tesseract_path);
+
+            let mut app = Self {
+                bot: AdvancedFishingBot::new(config.clone(), lifetime_stats),
+                applied_theme: config.theme,
+                config,
+                show_settings: false,
+                show_advanced_stats: false,
+                status_messages: vec![],
+                last_update: Instant::now(),
+                last_status: String::new(),
+                resolution_presets: presets,
+                window_size: egui::Vec2::new(900.0, 800.0),
+                applied_compact: false,
+                scale_factor: 1.0,
+                auto_start_at,
+                confirm_reset: false,
+                reset_clears_webhook: false,
+                cursor_capture: None,
+                region_nudge: RegionNudge::default(),
+                gauge_peak: (None, 0.0),
+                new_preset_name: String::new(),
+                resume_offer: config::SessionCheckpoint::load_recent(chrono::Duration::hours(
+                    RESUME_MAX_AGE_HOURS,
+                )),
+                accessibility_prompt,
+                #[cfg(target_os = "macos")]
+                safari_url: String::new(),
+            };
+
+            if app.config.self_check_on_startup {
+                app.run_self_check();
+            }
+            app
+        }
+
+        /// Logs a pass/fail line per [`AdvancedFishingBot::self_check`] item.
+        fn run_self_check(&mut self) {
+            let checks = self.bot.self_check();
+            let failed = checks
+                .iter()
+                .filter(|check| check.problem.is_some())
+                .count();
+            for check in checks {
+                self.update_status(match check.problem {
+                    None => tf("selfcheck.pass", &[("check", &check.name)]),
+                    Some(problem) => tf(
+                        "selfcheck.fail",
+                        &[("check", &check.name), ("problem", &problem)],
+                    ),
+                });
+            }
+            self.update_status(if failed == 0 {
+                t("selfcheck.all_passed").to_string()
+            } else {
+                tf("selfcheck.some_failed", &[("count", &failed)])
+            });
+        }
+
+        fn update_status(&mut self, message: String) {
+            let message = if self.config.plain_text_mode {
+                super::text::plain_text(&message)
+            } else {
+                message
+            };
+            let now = Local::now();
+            let timestamped_message = format!(
+                "[{:02}:{:02}:{:02}] {}",
+                now.hour(),
+                now.minute(),
+                now.second(),
+                message
+            );
+            self.status_messages.push((now, timestamped_message));
+            self.prune_status_messages();
+        }
+
+        fn prune_status_messages(&mut self) {
+            let retention = self.config.status_log_retention_minutes;
+            if retention > 0 {
+                let cutoff = Local::now() - chrono::Duration::minutes(retention as i64);
+                self.status_messages
+                    .retain(|(timestamp, _)| *timestamp >= cutoff);
+            }
+
+            let max = self.config.status_log_max_messages.max(1);
+            if self.status_messages.len() > max {
+                let excess = self.status_messages.len() - max;
+                self.status_messages.drain(..excess);
+            }
+        }
+    }
+
+    impl eframe::App for AdvancedFishingBotApp {
+        fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+            i18n::set_language(self.config.language);
+            if self.applied_theme != self.config.theme {
+                Self::apply_theme(ctx, self.config.theme);
+                self.applied_theme = self.config.theme;
+            }
+            // Age out old log lines even when nothing new is logged
+            self.prune_status_messages();
+
+            // Update window size and scale factor; the compact strip keeps the last full size
+            let current_size = ctx.screen_rect().size();
+            if !self.applied_compact && (current_size - self.window_size).length() > 10.0 {
+                self.window_size = current_size;
+                self.scale_factor = (current_size.x / 900.0)
+                    .min(current_size.y / 800.0)
+                    .clamp(0.5, 2.0);
+            }
+
+            // Window properties
+            if self.config.always_on_top {
+                ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
+                    egui::WindowLevel::AlwaysOnTop,
+                ));
+            }
+
+            // Update status from bot
+            if self.last_update.elapsed() > Duration::from_millis(100) {
+                let state = self.bot.get_state();
+                if !state.status.is_empty() && state.status != self.last_status {
+                    self.update_status(state.status.clone());
+                    self.last_status = state.status;
+                }
+                self.last_update = Instant::now();
+            }
+
+            // Start the unattended session once the launch countdown runs out
+            if let Some(at) = self.auto_start_at {
+                if self.bot.get_state().running {
+                    self.auto_start_at = None;
+                } else if Instant::now() >= at {
+                    self.auto_start_at = None;
+                    self.bot.start();
+                    self.update_status(t("status.auto_started").to_string());
+                }
+            }
+
+            self.finish_cursor_capture();
+            self.handle_shortcuts(ctx);
+
+            self.apply_window_mode(ctx);
+            if self.config.compact_mode {
+                self.render_compact_strip(ctx);
+                ctx.request_repaint_after(Duration::from_millis(100));
+                return;
+            }
+
+            CentralPanel::default().show(ctx, |ui| {
+                ScrollArea::vertical()
+                    .auto_shrink([true, true])
+                    .show(ui, |ui| {
+                        // Set dynamic spacing based on scale
+                        ui.spacing_mut().item_spacing =
+                            egui::vec2(8.0 * self.scale_factor, 6.0 * self.scale_factor);
+                        ui.spacing_mut().button_padding =
+                            egui::vec2(8.0 * self.scale_factor, 6.0 * self.scale_factor);
+
+                        // Enhanced Header
+                        self.render_header(ui);
+                        self.add_scaled_space(ui, 8.0);
+
+                        if self.bot.is_dry_run() {
+                            self.render_warning_banner(ui, t("controls.dry_run_banner"));
+                            self.add_scaled_space(ui, 8.0);
+                        }
+                        if let Some(error) = self.bot.input_init_error() {
+                            self.render_warning_banner(
+                                ui,
+                                &tf("status.input_unavailable", &[("error", &error)]),
+                            );
+                            self.add_scaled_space(ui, 8.0);
+                        }
+
+                        // Enhanced Control Panel
+                        self.render_control_panel(ui);
+                        self.add_scaled_space(ui, 12.0);
+
+                        // Status & Progress
+                        self.render_status_panel(ui);
+                        self.add_scaled_space(ui, 12.0);
+
+                        // Enhanced Statistics Panel
+                        self.render_statistics_panel(ui);
+                        self.add_scaled_space(ui, 12.0);
+
+                        // Performance Monitor
+                        self.render_performance_panel(ui);
+                        self.add_scaled_space(ui, 12.0);
+
+                        // Activity Monitor
+                        self.render_activity_monitor(ui);
+                        self.add_scaled_space(ui, 12.0);
+
+                        // Settings Panel - Now responsive
+                        self.render_bottom_buttons(ui);
+                    });
+            });
+
+            // Settings Window
+            if self.show_settings {
+                self.region_nudge.region = None;
+                self.render_settings_window(ctx);
+                self.render_region_preview(ctx);
+                if self.confirm_reset {
+                    self.render_reset_confirm(ctx);
+                }
+            } else {
+                self.confirm_reset = false;
+            }
+
+            // Advanced Statistics Window
+            if self.show_advanced_stats {
+                self.render_advanced_stats_window(ctx);
+            }
+
+            if self.accessibility_prompt {
+                self.render_accessibility_prompt(ctx);
+            }
+            if self.resume_offer.is_some() {
+                self.render_resume_offer(ctx);
+            }
+
+            ctx.request_repaint_after(Duration::from_millis(100));
+        }
+
+        fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+            self.bot.stop(StopReason::UserRequested);
+            self.bot.flush_webhooks(Duration::from_secs(3));
+            self.bot.save_pending_webhooks();
+            self.config.save().ok();
+        }
+    }
+
+    impl AdvancedFishingBotApp {
+        fn handle_shortcuts(&mut self, ctx: &Context) {
+            // Leave keys alone while a text field has focus
+            if ctx.wants_keyboard_input() {
+                return;
+            }
+
+            let (toggle_run, pause, settings, escape) = ctx.input(|i| {
+                (
+                    i.key_pressed(Key::Space),
+                    i.key_pressed(Key::P),
+                    i.key_pressed(Key::S),
+                    i.key_pressed(Key::Escape),
+                )
+            });
+
+            let state = self.bot.get_state();
+            if toggle_run {
+                if state.running {
+                    self.bot.stop(StopReason::UserRequested);
+                } else {
+                    self.bot.start();
+                }
+            }
+            if pause && state.running {
+                self.bot.pause();
+            }
+            if settings {
+                self.show_settings = true;
+            }
+            if escape {
+                if self.show_settings {
+                    // Same as Cancel: discard unsaved edits
+                    self.config = BotConfig::load().unwrap_or_default();
+                    self.show_settings = false;
+                }
+                self.show_advanced_stats = false;
+            }
+        }
+
+        fn render_control_panel(&mut self, ui: &mut Ui) {
+            self.aura_frame(self.panel_fill()).show(ui, |ui| {
+                let state = self.bot.get_state();
+                ui.heading(
+                    RichText::new(t("controls.title"))
+                        .size(self.scaled_font_size(18.0))
+                        .color(self.arcane_blue()),
+                );
+                ui.add_space(6.0 * self.scale_factor);
+
+                ui.horizontal(|ui| {
+                    let button_size = self.scaled_button_size(140.0, 54.0);
+
+                    let start_enabled = !state.running;
+                    let start_button = Button::new(
+                        RichText::new(t("controls.start"))
+                            .size(self.scaled_font_size(16.0))
+                            .strong()
+                            .color(Color32::BLACK),
+                    )
+                    .min_size(button_size)
+                    .fill(if start_enabled {
+                        self.emerald()
+                    } else {
+                        Color32::from_rgba_unmultiplied(40, 60, 50, 140)
+                    });
+
+                    if ui
+                        .add_enabled(start_enabled, start_button)
+                        .on_hover_text(t("controls.start_hint"))
+                        .clicked()
+                    {
+                        self.bot.start();
+                    }
+
+                    let pause_label = if state.paused {
+                        t("controls.resume")
+                    } else {
+                        t("controls.pause")
+                    };
+                    let pause_color = if state.paused {
+                        self.emerald()
+                    } else {
+                        Color32::from_rgb(200, 170, 60)
+                    };
+
+                    if ui
+                        .add_enabled(
+                            state.running,
+                            Button::new(
+                                RichText::new(pause_label)
+                                    .size(self.scaled_font_size(16.0))
+                                    .strong(),
+                            )
+                            .min_size(button_size)
+                            .fill(pause_color),
+                        )
+                        .on_hover_text(t("controls.pause_hint"))
+                        .clicked()
+                    {
+                        self.bot.pause();
+                    }
+
+                    if ui
+                        .add_enabled(
+                            state.running,
+                            Button::new(
+                                RichText::new(t("controls.stop"))
+                                    .size(self.scaled_font_size(16.0))
+                                    .strong(),
+                            )
+                            .min_size(button_size)
+                            .fill(self.ember_red()),
+                        )
+                        .on_hover_text(t("controls.stop_hint"))
+                        .clicked()
+                    {
+                        self.bot.stop(StopReason::UserRequested);
+                    }
+                });
+
+                if let Some(at) = self.auto_start_at {
+                    let remaining = at.saturating_duration_since(Instant::now()).as_secs() + 1;
+                    ui.add_space(6.0 * self.scale_factor);
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            self.gold_glow(),
+                            tf("controls.auto_start_in", &[("secs", &remaining)]),
+                        );
+                        if ui.button(t("controls.cancel_auto_start")).clicked() {
+                            self.auto_start_at = None;
+                            self.update_status(t("status.auto_start_cancelled").to_string());
+                        }
+                    });
+                }
+
+                if self.config.dev_mode {
+                    ui.add_space(6.0 * self.scale_factor);
+                    if ui.button(t("controls.simulate_catch")).clicked()
+                        && !self.bot.simulate_catch()
+                    {
+                        self.update_status(t("status.simulate_needs_dry_run").to_string());
+                    }
+                }
+            });
+        }
+
+        fn render_status_panel(&mut self, ui: &mut Ui) {
+            let state = self.bot.get_state();
+            self.aura_frame(self.inset_fill()).show(ui, |ui| {
+                let time = ui.ctx().input(|i| i.time);
+                let glow = ((time as f32).sin() + 1.0) * 0.5;
+                let icon_color =
+                    Color32::from_rgb(120, (80.0 + 60.0 * glow) as u8, (180.0 + 40.0 * glow) as u8);
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new("🪄")
+                            .size(self.scaled_font_size(28.0))
+                            .color(icon_color),
+                    );
+
+                    ui.vertical(|ui| {
+                        let phase_text = Self::phase_label(&state.current_phase);
+
+                        ui.label(
+                            RichText::new(phase_text)
+                                .strong()
+                                .size(self.scaled_font_size(17.0))
+                                .color(self.gold_glow()),
+                        );
+                        ui.label(
+                            RichText::new(&state.status)
+                                .color(self.arcane_blue())
+                                .size(self.scaled_font_size(13.0)),
+                        );
+                        if let Some(error) = ocr::tesseract_error() {
+                            ui.colored_label(
+                                self.ember_red(),
+                                tf("status.tesseract_missing", &[("error", &error)]),
+                            );
+                        } else if state.hunger_ocr_failures >= bot::HUNGER_OCR_FAILURE_LIMIT {
+                            ui.colored_label(self.ember_red(), t("status.hunger_ocr_failing"));
+                        }
+                    });
+                });
+
+                ui.add_space(10.0 * self.scale_factor);
+
+                let progress = self.phase_progress(&state.current_phase);
+                let bar = egui::ProgressBar::new(progress)
+                    .desired_width(ui.available_width())
+                    .fill(Color32::from_rgb(60, 40, 90))
+                    .animate(true)
+                    .text(t("status_panel.progress"));
+                ui.add(bar);
+            });
+        }
+
+        fn render_statistics_panel(&mut self, ui: &mut Ui) {
+            self.aura_frame(self.panel_fill()).show(ui, |ui| {
+                let state = self.bot.get_state();
+                let lifetime = self.bot.get_lifetime_stats();
+
+                ui.heading(
+                    RichText::new(t("metrics.title"))
+                        .color(self.gold_glow())
+                        .size(self.scaled_font_size(18.0)),
+                );
+                ui.add_space(6.0 * self.scale_factor);
+
+                ui.horizontal(|ui| {
+                    // The trailing-window rate; the whole-session average swings early on
+                    let fish_per_hour = if state.running {
+                        state.smoothed_fish_per_hour
+                    } else {
+                        Some(state.fish_per_hour)
+                    };
+                    let rate_max = self.fish_rate_gauge_max(
+                        state.start_time,
+                        &[fish_per_hour, Some(lifetime.average_fish_per_hour)],
+                    );
+                    self.draw_gauge(
+                        ui,
+                        fish_per_hour,
+                        rate_max,
+                        t("metrics.fish_per_hour"),
+                        self.arcane_blue(),
+                    );
+                    self.draw_gauge(
+                        ui,
+                        state.uptime_percentage,
+                        100.0,
+                        t("metrics.uptime"),
+                        self.gold_glow(),
+                    );
+                    self.draw_gauge(
+                        ui,
+                        Some(lifetime.average_fish_per_hour),
+                        rate_max,
+                        t("metrics.lifetime_pace"),
+                        self.arcane_purple(),
+                    );
+                });
+
+                ui.add_space(10.0 * self.scale_factor);
+                ui.horizontal(|ui| {
+                    self.render_digital_counter(
+                        ui,
+                        t("metrics.session_fish"),
+                        &format!("{}", state.fish_count),
+                        self.arcane_blue(),
+                    );
+                    self.render_digital_counter(
+                        ui,
+                        t("metrics.best_streak"),
+                        &format!("{}", state.session_best_streak),
+                        self.gold_glow(),
+                    );
+                    self.render_digital_counter(
+                        ui,
+                        t("metrics.total_fish"),
+                        &format!("{}", lifetime.total_fish_caught),
+                        self.emerald(),
+                    );
+                    let runtime = if let Some(start) = state.start_time {
+                        let elapsed = start.elapsed();
+                        format!(
+                            "{:02}:{:02}:{:02}",
+                            elapsed.as_secs() / 3600,
+                            (elapsed.as_secs() % 3600) / 60,
+                            elapsed.as_secs() % 60
+                        )
+                    } else {
+                        "00:00:00".to_string()
+                    };
+                    self.render_digital_counter(
+                        ui,
+                        t("metrics.session_time"),
+                        &runtime,
+                        self.arcane_purple(),
+                    );
+                    if let Some(delta) = state.session_gold_delta {
+                        self.render_digital_counter(
+                            ui,
+                            t("metrics.session_gold"),
+                            &format!("{:+}", delta),
+                            self.gold_glow(),
+                        );
+                    }
+                    if self.config.session_fish_goal > 0 {
+                        let eta = Self::goal_eta(
+                            state.fish_count,
+                            self.config.session_fish_goal as u64,
+                            state.smoothed_fish_per_hour,
+                        );
+                        self.render_digital_counter(ui, t("metrics.eta"), &eta, self.emerald());
+                    }
+                });
+            });
+        }
+
+        fn goal_eta(caught: u64, goal: u64, rate: Option<f32>) -> String {
+            if caught >= goal {
+                return t("metrics.goal_reached").to_string();
+            }
+            match rate {
+                Some(rate) if rate > 0.0 => {
+                    let minutes = ((goal - caught) as f32 / rate * 60.0).ceil() as u64;
+                    format!("~{}h {:02}m", minutes / 60, minutes % 60)
+                }
+                _ => "—".to_string(),
+            }
+        }
+
+        /// Top of the fish/hr gauges: the configured max, or with auto-scaling the
+        /// session's fastest rate plus 25% headroom, whichever is higher.
+        fn fish_rate_gauge_max(&mut self, session: Option<Instant>, rates: &[Option<f32>]) -> f32 {
+            let floor = self.config.gauge_max_fish_per_hour.max(1.0);
+            if !self.config.gauge_auto_scale {
+                return floor;
+            }
+
+            if self.gauge_peak.0 != session {
+                self.gauge_peak = (session, 0.0);
+            }
+            for rate in rates.iter().flatten() {
+                self.gauge_peak.1 = self.gauge_peak.1.max(*rate);
+            }
+            // Round to a multiple of 10 so the scale doesn't creep every frame
+            let scaled = (self.gauge_peak.1 * 1.25 / 10.0).ceil() * 10.0;
+            scaled.max(floor)
+        }
+
+        fn percent_or_dash(value: Option<f32>) -> String {
+            value
+                .map(|value| format!("{:.1}%", value))
+                .unwrap_or_else(|| "—".to_string())
+        }
+
+        /// Dial for `value` out of `max`; `None` parks the needle and reads "warming up".
+        fn draw_gauge(
+            &self,
+            ui: &mut Ui,
+            value: Option<f32>,
+            max: f32,
+            label: &str,
+            color: Color32,
+        ) {
+            let size = 110.0 * self.scale_factor;
+            let (rect, _) = ui.allocate_exact_size(vec2(size, size), Sense::hover());
+            let painter = ui.painter_at(rect);
+
+            let center = rect.center();
+            let radius = size * 0.45;
+            painter.circle_stroke(center, radius, Stroke::new(2.0, self.arcane_purple()));
+
+            let ratio = (value.unwrap_or(0.0) / max).clamp(0.0, 1.0);
+            let start = -PI * 0.75;
+            let sweep = PI * 1.5 * ratio;
+            let steps = 32;
+            let mut points = Vec::with_capacity(steps + 1);
+            for i in 0..=steps {
+                let t = start + sweep * (i as f32 / steps as f32);
+                points.push(center + vec2(t.cos() * radius, t.sin() * radius));
+            }
+            painter.add(epaint::PathShape {
+                points,
+                closed: false,
+                fill: Color32::TRANSPARENT,
+                stroke: Stroke::new(4.0, color),
+            });
+
+            let needle_angle = start + sweep;
+            let needle = center
+                + vec2(
+                    needle_angle.cos() * radius * 0.9,
+                    needle_angle.sin() * radius * 0.9,
+                );
+            painter.line_segment([center, needle], Stroke::new(3.0, self.palette().text));
+
+            painter.circle_filled(center, 6.0, self.gold_glow());
+
+            let (text, font_size) = match value {
+                Some(value) => (format!("{:.0}", value), 14.0),
+                None => (t("metrics.warming_up").to_string(), 10.0),
+            };
+            painter.text(
+                center,
+                Align2::CENTER_CENTER,
+                text,
+                FontId::proportional(self.scaled_font_size(font_size)),
+                self.palette().text,
+            );
+            painter.text(
+                center + vec2(0.0, radius * 0.45),
+                Align2::CENTER_CENTER,
+                tf("metrics.gauge_max", &[("max", &format!("{:.0}", max))]),
+                FontId::proportional(self.scaled_font_size(9.0)),
+                self.palette().muted_text,
+            );
+
+            let label_pos = egui::pos2(center.x, rect.bottom() + 4.0 * self.scale_factor);
+            painter.text(
+                label_pos,
+                Align2::CENTER_TOP,
+                label,
+                FontId::proportional(self.scaled_font_size(12.0)),
+                self.palette().muted_text,
+            );
+        }
+
+        fn render_digital_counter(&self, ui: &mut Ui, label: &str, value: &str, color: Color32) {
+            Frame::none()
+                .fill(self.inset_fill())
+                .stroke(self.rune_border())
+                .rounding(6.0)
+                .inner_margin(8.0 * self.scale_factor)
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(
+                            RichText::new(label)
+                                .size(self.scaled_font_size(12.0))
+                                .color(self.gold_glow()),
+                        );
+                        ui.label(
+                            RichText::new(value)
+                                .size(self.scaled_font_size(18.0))
+                                .color(color)
+                                .monospace()
+                                .strong(),
+                        );
+                    });
+                });
+        }
+
+        fn render_performance_panel(&mut self, ui: &mut Ui) {
+            self.aura_frame(self.panel_fill()).show(ui, |ui| {
+                ui.heading(
+                    RichText::new(t("performance.title"))
+                        .color(self.arcane_blue())
+                        .size(self.scaled_font_size(16.0)),
+                );
+                ui.separator();
+
+                let (success_rate, avg_time, error_count) = self.bot.get_performance_stats();
+                let scan_rate = self.bot.get_scan_rate();
+                let state = self.bot.get_state();
+
+                Grid::new("perf_grid")
+                    .num_columns(4)
+                    .spacing([20.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(t("performance.success_rate")).strong());
+                        let success_color = if success_rate > 95.0 {
+                            self.emerald()
+                        } else if success_rate > 85.0 {
+                            self.gold_glow()
+                        } else {
+                            self.ember_red()
+                        };
+                        ui.label(
+                            RichText::new(format!("✅ {:.1}%", success_rate)).color(success_color),
+                        );
+
+                        ui.label(RichText::new(t("performance.uptime")).strong());
+                        let uptime_color = if state.uptime_percentage.is_some_and(|u| u > 95.0) {
+                            self.emerald()
+                        } else {
+                            self.gold_glow()
+                        };
+                        ui.label(
+                            RichText::new(format!(
+                                "📈 {}",
+                                Self::percent_or_dash(state.uptime_percentage)
+                            ))
+                            .color(uptime_color),
+                        );
+                        ui.end_row();
+
+                        ui.label(RichText::new(t("performance.avg_operation")).strong());
+                        ui.label(
+                            RichText::new(format!("⏱️ {:.1}ms", avg_time.as_millis()))
+                                .color(self.arcane_blue()),
+                        );
+
+                        ui.label(RichText::new(t("performance.total_errors")).strong());
+                        let error_color = if error_count == 0 {
+                            self.emerald()
+                        } else if error_count < 5 {
+                            self.gold_glow()
+                        } else {
+                            self.ember_red()
+                        };
+                        ui.label(RichText::new(format!("❌ {}", error_count)).color(error_color));
+                        ui.end_row();
+
+                        ui.label(RichText::new(t("performance.scan_rate")).strong());
+                        match scan_rate {
+                            Some((rate, target)) => {
+                                // Well below target means capture can't keep up
+                                let rate_color = if rate < target * 0.75 {
+                                    self.ember_red()
+                                } else {
+                                    self.emerald()
+                                };
+                                ui.label(
+                                    RichText::new(tf(
+                                        "performance.scan_rate_value",
+                                        &[
+                                            ("rate", &format!("{:.0}", rate)),
+                                            ("target", &format!("{:.0}", target)),
+                                        ],
+                                    ))
+                                    .color(rate_color),
+                                );
+                            }
+                            None => {
+                                ui.label(t("performance.scan_idle"));
+                            }
+                        }
+                        ui.end_row();
+
+                        let (bite_history, catch_history) = self.bot.match_history();
+                        ui.label(RichText::new(t("performance.bite_matches")).strong());
+                        self.match_sparkline(ui, &bite_history, self.ember_red());
+                        ui.label(RichText::new(t("performance.catch_matches")).strong());
+                        self.match_sparkline(ui, &catch_history, self.gold_glow());
+                        ui.end_row();
+                    });
+            });
+        }
+
+        /// Line of recent match counts scaled to their own range, with the latest
+        /// value beside it, so drift in detection strength is visible at a glance.
+        fn match_sparkline(&self, ui: &mut Ui, values: &[u32], color: Color32) {
+            ui.horizontal(|ui| {
+                let size = vec2(120.0, 20.0) * self.scale_factor;
+                let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                let (Some(&min), Some(&max), Some(&latest)) =
+                    (values.iter().min(), values.iter().max(), values.last())
+                else {
+                    ui.label("—");
+                    return;
+                };
+
+                if values.len() > 1 {
+                    let span = (max - min).max(1) as f32;
+                    let step = rect.width() / (values.len() - 1) as f32;
+                    let points = values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &value)| {
+                            pos2(
+                                rect.left() + step * i as f32,
+                                rect.bottom() - rect.height() * (value - min) as f32 / span,
+                            )
+                        })
+                        .collect();
+                    ui.painter()
+                        .add(Shape::line(points, Stroke::new(1.5, color)));
+                }
+                response.on_hover_text(tf(
+                    "performance.matches_range",
+                    &[("min", &min), ("max", &max), ("count", &values.len())],
+                ));
+                ui.label(RichText::new(latest.to_string()).color(color));
+            });
+        }
+
+        fn render_activity_monitor(&mut self, ui: &mut Ui) {
+            Frame::none()
+                .fill(self.palette().log_fill)
+                .stroke(self.rune_border())
+                .rounding(10.0)
+                .inner_margin(14.0 * self.scale_factor)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.heading(
+                            RichText::new(t("log.title"))
+                                .color(self.gold_glow())
+                                .size(self.scaled_font_size(16.0)),
+                        );
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui
+                                .button(
+                                    RichText::new("🧹")
+                                        .color(self.gold_glow())
+                                        .size(self.scaled_font_size(14.0)),
+                                )
+                                .clicked()
+                            {
+                                self.status_messages.clear();
+                            }
+                        });
+                    });
+                    ui.separator();
+
+                    ScrollArea::vertical()
+                        .max_height(180.0 * self.scale_factor)
+                        .auto_shrink([false; 2])
+                        .show(ui, |ui| {
+                            for (_timestamp, message) in self.status_messages.iter().rev() {
+                                ui.label(
+                                    RichText::new(message)
+                                        .family(FontFamily::Proportional)
+                                        .color(self.palette().log_text),
+                                );
+                            }
+                        });
+                });
+        }
+
+        fn render_settings_window(&mut self, ctx: &Context) {
+            Window::new(t("settings.title"))
+                .id(Id::new("settings_window"))
+                .default_size([700.0, 600.0])
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ScrollArea::vertical().show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("settings.language"));
+                            ComboBox::from_id_source("language")
+                                .selected_text(self.config.language.display_name())
+                                .show_ui(ui, |ui| {
+                                    for lang in Lang::ALL {
+                                        ui.selectable_value(
+                                            &mut self.config.language,
+                                            lang,
+                                            lang.display_name(),
+                                        );
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(t("settings.theme"));
+                            ComboBox::from_id_source("theme")
+                                .selected_text(self.config.theme.label())
+                                .show_ui(ui, |ui| {
+                                    for theme in Theme::ALL {
+                                        ui.selectable_value(
+                                            &mut self.config.theme,
+                                            theme,
+                                            theme.label(),
+                                        );
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(t("settings.log_limit"));
+                            ui.add(
+                                DragValue::new(&mut self.config.status_log_max_messages)
+                                    .clamp_range(10..=5000)
+                                    .suffix(t("settings.log_messages")),
+                            );
+                            ui.add(
+                                DragValue::new(&mut self.config.status_log_retention_minutes)
+                                    .clamp_range(0..=1440)
+                                    .suffix(t("settings.log_minutes")),
+                            )
+                            .on_hover_text(t("settings.log_minutes_hint"));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(t("settings.gauge_max"));
+                            ui.add(
+                                DragValue::new(&mut self.config.gauge_max_fish_per_hour)
+                                    .clamp_range(10.0..=2000.0)
+                                    .speed(5.0),
+                            );
+                            ui.checkbox(
+                                &mut self.config.gauge_auto_scale,
+                                t("settings.gauge_auto_scale"),
+                            )
+                            .on_hover_text(t("settings.gauge_auto_scale_hint"));
+                        });
+                        ui.separator();
+
+                        // Basic Settings
+                        CollapsingHeader::new(t("settings.detection"))
+                            .id_source("settings_detection")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                Grid::new("detection_settings")
+                                    .num_columns(2)
+                                    .spacing([20.0, 8.0])
+                                    .show(ui, |ui| {
+                                        ui.label(t("settings.color_tolerance"));
+                                        ui.add(
+                                            Slider::new(&mut self.config.color_tolerance, 1..=50)
+                                                .text(t("settings.unit_pixels")),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.low_light_boost"));
+                                        ui.add(Slider::new(
+                                            &mut self.config.low_light_tolerance_boost,
+                                            0..=50,
+                                        ))
+                                        .on_hover_text(t("settings.low_light_boost_hint"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.low_light_below"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.low_light_brightness,
+                                                1..=255,
+                                            )
+                                            .text(t("settings.unit_brightness")),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.min_match_pixels"));
+                                        ui.horizontal(|ui| {
+                                            ui.add_enabled(
+                                                !self.config.match_fraction_enabled,
+                                                Slider::new(
+                                                    &mut self.config.min_match_pixels,
+                                                    1..=200,
+                                                )
+                                                .text(t("settings.unit_pixels")),
+                                            );
+                                            ui.label(tf(
+                                                "settings.live_pixels",
+                                                &[(
+                                                    "count",
+                                                    &self.bot.get_state().bite_match_pixels,
+                                                )],
+                                            ));
+                                        });
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.match_fraction_enabled,
+                                            t("settings.match_fraction"),
+                                        )
+                                        .on_hover_text(t("settings.match_fraction_hint"));
+                                        let mut percent = self.config.min_match_fraction * 100.0;
+                                        if ui
+                                            .add_enabled(
+                                                self.config.match_fraction_enabled,
+                                                Slider::new(&mut percent, 0.01..=5.0)
+                                                    .logarithmic(true)
+                                                    .suffix(t("settings.unit_percent_pixels")),
+                                            )
+                                            .changed()
+                                        {
+                                            self.config.min_match_fraction = percent / 100.0;
+                                        }
+                                        ui.end_row();
+
+                                        ui.label(t("settings.downsample"));
+                                        ui.horizontal(|ui| {
+                                            ui.add(
+                                                Slider::new(
+                                                    &mut self.config.detection_downsample,
+                                                    1..=4,
+                                                )
+                                                .text(t("settings.unit_px_step")),
+                                            )
+                                            .on_hover_text(t("settings.downsample_hint"));
+                                            if self.config.detection_downsample > 2 {
+                                                ui.colored_label(
+                                                    self.ember_red(),
+                                                    t("settings.downsample_warning"),
+                                                );
+                                            }
+                                        });
+                                        ui.end_row();
+
+                                        ui.label(t("settings.capture_cache"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.screenshot_cache_ms,
+                                                0..=500,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.bite_scan_interval"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.bite_scan_interval_ms,
+                                                10..=200,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.advanced_detection,
+                                            t("settings.advanced_detection"),
+                                        );
+                                        ui.label(t("settings.advanced_detection_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.min_cluster_size"));
+                                        ui.add_enabled(
+                                            self.config.advanced_detection
+                                                && !self.config.match_fraction_enabled,
+                                            Slider::new(
+                                                &mut self.config.min_cluster_pixels,
+                                                1..=200,
+                                            )
+                                            .text("px"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.adaptive_timing,
+                                            t("settings.adaptive_timing"),
+                                        );
+                                        ui.label(t("settings.adaptive_timing_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.target_scan_rate"));
+                                        ui.add_enabled(
+                                            self.config.adaptive_timing,
+                                            Slider::new(
+                                                &mut self.config.target_scan_rate_hz,
+                                                1..=60,
+                                            )
+                                            .text("Hz"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.bite_detection_mode"));
+                                        ComboBox::from_id_source("bite_detection_mode")
+                                            .selected_text(match self.config.bite_detection_mode {
+                                                BiteDetectionMode::Color => {
+                                                    t("settings.bite_mode_color")
+                                                }
+                                                BiteDetectionMode::MotionDelta => {
+                                                    t("settings.bite_mode_motion")
+                                                }
+                                            })
+                                            .show_ui(ui, |ui| {
+                                                ui.selectable_value(
+                                                    &mut self.config.bite_detection_mode,
+                                                    BiteDetectionMode::Color,
+                                                    t("settings.bite_mode_color"),
+                                                );
+                                                ui.selectable_value(
+                                                    &mut self.config.bite_detection_mode,
+                                                    BiteDetectionMode::MotionDelta,
+                                                    t("settings.bite_mode_motion"),
+                                                );
+                                            });
+                                        ui.end_row();
+
+                                        ui.label(t("settings.motion_threshold"));
+                                        ui.add_enabled(
+                                            self.config.bite_detection_mode
+                                                == BiteDetectionMode::MotionDelta,
+                                            Slider::new(
+                                                &mut self.config.motion_delta_threshold,
+                                                1.0..=100.0,
+                                            )
+                                            .text(t("settings.unit_avg_diff")),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.display"));
+                                        ui.add(
+                                            DragValue::new(&mut self.config.screen_index)
+                                                .clamp_range(0..=7),
+                                        )
+                                        .on_hover_text(t("settings.display_hint"));
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.window_relative_regions,
+                                            t("settings.window_relative_regions"),
+                                        );
+                                        ui.label(t("settings.window_relative_regions_hint"));
+                                        ui.end_row();
+                                    });
+                            });
+
+                        // Fishing Settings
+                        CollapsingHeader::new(t("settings.fishing"))
+                            .id_source("settings_fishing")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                Grid::new("fishing_settings")
+                                    .num_columns(2)
+                                    .spacing([20.0, 8.0])
+                                    .show(ui, |ui| {
+                                        ui.label(t("settings.cast_click_position"));
+                                        ui.horizontal(|ui| {
+                                            Self::position_editor(
+                                                ui,
+                                                t("settings.click_fixed"),
+                                                &mut self.config.cast_click_pos,
+                                            );
+                                            self.cursor_capture_button(ui, CursorTarget::Cast);
+                                        });
+                                        ui.end_row();
+
+                                        ui.label(t("settings.reel_click_position"));
+                                        ui.horizontal(|ui| {
+                                            ui.add_enabled_ui(
+                                                !self.config.reel_click_in_region,
+                                                |ui| {
+                                                    Self::position_editor(
+                                                        ui,
+                                                        t("settings.click_fixed"),
+                                                        &mut self.config.reel_click_pos,
+                                                    );
+                                                    self.cursor_capture_button(
+                                                        ui,
+                                                        CursorTarget::Reel,
+                                                    );
+                                                },
+                                            );
+                                        });
+                                        ui.end_row();
+
+                                        ui.label("");
+                                        ui.horizontal(|ui| {
+                                            ui.checkbox(
+                                                &mut self.config.reel_click_in_region,
+                                                t("settings.click_in_catch_region"),
+                                            )
+                                            .on_hover_text(t(
+                                                "settings.click_in_catch_region_hint",
+                                            ));
+                                            let [fx, fy] = &mut self.config.reel_click_offset;
+                                            ui.add_enabled_ui(
+                                                self.config.reel_click_in_region,
+                                                |ui| {
+                                                    ui.label("X");
+                                                    ui.add(
+                                                        DragValue::new(fx)
+                                                            .speed(0.01)
+                                                            .clamp_range(0.0..=1.0),
+                                                    );
+                                                    ui.label("Y");
+                                                    ui.add(
+                                                        DragValue::new(fy)
+                                                            .speed(0.01)
+                                                            .clamp_range(0.0..=1.0),
+                                                    );
+                                                },
+                                            );
+                                        });
+                                        ui.end_row();
+
+                                        ui.label(t("settings.reel_click_interval"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.autoclick_interval_ms,
+                                                10..=200,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.reel_pattern"));
+                                        Self::reel_pattern_editor(
+                                            ui,
+                                            &mut self.config.reel_click_pattern_ms,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.reel_scan_interval"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.reel_scan_interval_ms,
+                                                10..=500,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.cast_settle_delay"));
+                                        ui.add(
+                                            Slider::new(&mut self.config.cast_settle_ms, 0..=2000)
+                                                .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.post_catch_delay"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.post_catch_delay_ms,
+                                                0..=2000,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.fish_per_feed"));
+                                        ui.add(Slider::new(&mut self.config.fish_per_feed, 1..=20));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.safety_feed_cooldown"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.safety_feed_cooldown_secs,
+                                                0..=1800,
+                                            )
+                                            .text(t("settings.unit_seconds")),
+                                        )
+                                        .on_hover_text(t("settings.safety_feed_cooldown_hint"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.feed_jitter"));
+                                        ui.add(
+                                            Slider::new(&mut self.config.feed_jitter, 0..=10)
+                                                .text(t("settings.unit_fish")),
+                                        )
+                                        .on_hover_text(t("settings.feed_jitter_hint"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.rod_lure_value"));
+                                        ui.add(
+                                            Slider::new(&mut self.config.rod_lure_value, 0.1..=5.0)
+                                                .step_by(0.1),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.bite_timeout"));
+                                        ui.label(self.config.get_timeout_description());
+                                        ui.end_row();
+
+                                        ui.label(t("settings.max_fishing_timeout"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.max_fishing_timeout_ms,
+                                                5000..=60000,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.session_goal"));
+                                        ui.add(
+                                            DragValue::new(&mut self.config.session_fish_goal)
+                                                .clamp_range(0..=100000)
+                                                .suffix(t("settings.unit_fish_goal")),
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.stop_at_session_goal,
+                                            t("settings.stop_at_session_goal"),
+                                        );
+                                        ui.label(t("settings.stop_at_session_goal_desc"));
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.rod_check_enabled,
+                                            t("settings.check_rod_equipped"),
+                                        );
+                                        ui.label(t("settings.check_rod_equipped_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.rod_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("rod_region"),
+                                            &mut self.config.rod_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.equipped_color"));
+                                        ui.color_edit_button_srgb(
+                                            &mut self.config.rod_equipped_color,
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.popup_check_enabled,
+                                            t("settings.dismiss_popups"),
+                                        );
+                                        ui.label(t("settings.dismiss_popups_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.popup_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("popup_region"),
+                                            &mut self.config.popup_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.popup_color"));
+                                        ui.color_edit_button_srgb(&mut self.config.popup_color);
+                                        ui.end_row();
+
+                                        ui.label(t("settings.dismiss_with"));
+                                        Self::popup_dismiss_editor(
+                                            ui,
+                                            &mut self.config.popup_dismiss,
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.minigame_start_check_enabled,
+                                            t("settings.wait_for_minigame"),
+                                        );
+                                        ui.label(t("settings.wait_for_minigame_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.minigame_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("minigame_start_region"),
+                                            &mut self.config.minigame_start_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.minigame_color"));
+                                        ui.color_edit_button_srgb(
+                                            &mut self.config.minigame_start_color,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.minigame_timeout"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.minigame_start_timeout_ms,
+                                                200..=5000,
+                                            )
+                                            .text("ms"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.checkbox(
+                                            &mut self.config.gold_tracking_enabled,
+                                            t("settings.track_gold"),
+                                        );
+                                        ui.label(t("settings.track_gold_desc"));
+                                        ui.end_row();
+
+                                        ui.label(t("settings.gold_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("gold_region"),
+                                            &mut self.config.gold_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.gold_check_interval"));
+                                        ui.add(
+                                            Slider::new(
+                                                &mut self.config.gold_check_interval_secs,
+                                                10..=600,
+                                            )
+                                            .text("s"),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.catch_log"));
+                                        ui.checkbox(
+                                            &mut self.config.catch_log_csv,
+                                            t("settings.catch_log_desc"),
+                                        )
+                                        .on_hover_text(
+                                            config::CatchLog::path().display().to_string(),
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.match_log"));
+                                        ui.checkbox(
+                                            &mut self.config.match_log_csv,
+                                            t("settings.match_log_desc"),
+                                        )
+                                        .on_hover_text(
+                                            config::MatchLog::path().display().to_string(),
+                                        );
+                                        ui.end_row();
+                                    });
+                            });
+
+                        // Input Sequences
+                        CollapsingHeader::new(t("settings.sequences"))
+                            .id_source("settings_sequences")
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                ui.label(t("settings.rod_reset_sequence"));
+                                ui.push_id("rod_reset_sequence", |ui| {
+                                    Self::sequence_editor(
+                                        ui,
+                                        &mut self.config.rod_reset_sequence,
+                                        InputStep::default_rod_reset,
+                                    );
+                                });
+                                ui.separator();
+
+                                ui.label(t("settings.feed_sequence"));
+                                ui.push_id("feed_sequence", |ui| {
+                                    Self::sequence_editor(
+                                        ui,
+                                        &mut self.config.feed_sequence,
+                                        InputStep::default_feed,
+                                    );
+                                });
+                            });
+
+                        // Fishing Spots
+                        CollapsingHeader::new(t("settings.spots"))
+                            .id_source("settings_spots")
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                ui.checkbox(
+                                    &mut self.config.spot_rotation_enabled,
+                                    t("settings.rotate_spots"),
+                                );
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.move_after"));
+                                    ui.add(
+                                        DragValue::new(&mut self.config.spot_rotation_minutes)
+                                            .clamp_range(0..=600)
+                                            .suffix(" min"),
+                                    );
+                                    ui.label(t("settings.or"));
+                                    ui.add(
+                                        DragValue::new(&mut self.config.spot_rotation_fish)
+                                            .clamp_range(0..=10000)
+                                            .suffix(t("settings.unit_fish_suffix")),
+                                    );
+                                    ui.label(t("settings.zero_off"));
+                                });
+                                ui.separator();
+
+                                let mut remove_spot = None;
+                                for (index, spot) in self.config.spots.iter_mut().enumerate() {
+                                    ui.push_id(("spot", index), |ui| {
+                                        if Self::spot_editor(ui, spot, &mut self.region_nudge) {
+                                            remove_spot = Some(index);
+                                        }
+                                    });
+                                }
+                                if let Some(index) = remove_spot {
+                                    self.config.spots.remove(index);
+                                }
+
+                                if ui.button(t("settings.add_spot")).clicked() {
+                                    let spot = Spot {
+                                        name: format!("Spot {}", self.config.spots.len() + 1),
+                                        cast_position: None,
+                                        red_region: self.config.red_region,
+                                        yellow_region: self.config.yellow_region,
+                                        movement: Vec::new(),
+                                    };
+                                    self.config.spots.push(spot);
+                                }
+                            });
+
+                        // Safety Settings
+                        CollapsingHeader::new(t("settings.safety"))
+                            .id_source("settings_safety")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                ui.checkbox(
+                                    &mut self.config.failsafe_enabled,
+                                    t("settings.failsafe"),
+                                );
+                                ui.checkbox(&mut self.config.input_dry_run, t("settings.dry_run"))
+                                    .on_hover_text(t("settings.restart_hint"));
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.windows_input_method"));
+                                    ComboBox::from_id_source("windows_input_method")
+                                        .selected_text(self.config.windows_input_method.label())
+                                        .show_ui(ui, |ui| {
+                                            for method in WindowsInputMethod::ALL {
+                                                ui.selectable_value(
+                                                    &mut self.config.windows_input_method,
+                                                    method,
+                                                    method.label(),
+                                                );
+                                            }
+                                        });
+                                })
+                                .response
+                                .on_hover_text(t("settings.windows_input_method_hint"));
+                                ui.checkbox(
+                                    &mut self.config.auto_save_enabled,
+                                    t("settings.auto_save"),
+                                );
+                                ui.checkbox(
+                                    &mut self.config.self_check_on_startup,
+                                    t("settings.self_check_on_launch"),
+                                );
+                                ui.checkbox(
+                                    &mut self.config.plain_text_mode,
+                                    t("settings.plain_text"),
+                                );
+
+                                ui.checkbox(
+                                    &mut self.config.blank_frame_pause_enabled,
+                                    t("settings.blank_frame_pause"),
+                                );
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.blank_frame_threshold"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.blank_frame_ratio,
+                                            90.0..=100.0,
+                                        )
+                                        .text(t("settings.unit_percent_near_black")),
+                                    );
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.loading_check_enabled,
+                                    t("settings.loading_screen_pause"),
+                                );
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.loading_region"));
+                                    Self::region_editor(
+                                        ui,
+                                        Id::new("loading_region"),
+                                        &mut self.config.loading_region,
+                                        &mut self.region_nudge,
+                                    );
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.loading_color"));
+                                    ui.color_edit_button_srgb(&mut self.config.loading_color);
+                                    ui.add(
+                                        Slider::new(&mut self.config.loading_ratio, 50.0..=100.0)
+                                            .text(t("settings.unit_percent_region")),
+                                    );
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.low_success_pause_enabled,
+                                    t("settings.low_success_pause"),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.min_catch_rate"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.low_success_rate_floor,
+                                            5.0..=95.0,
+                                        )
+                                        .text("%"),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.over_last"));
+                                    ui.add(
+                                        Slider::new(&mut self.config.low_success_window, 10..=200)
+                                            .text(t("settings.unit_casts")),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.reel_timeout_pause"));
+                                    ui.add(
+                                        Slider::new(&mut self.config.max_reel_timeouts, 0..=20)
+                                            .text(t("settings.unit_in_a_row")),
+                                    );
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.anti_afk_enabled,
+                                    t("settings.anti_afk"),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.anti_afk_after"));
+                                    ui.add_enabled(
+                                        self.config.anti_afk_enabled,
+                                        Slider::new(&mut self.config.anti_afk_idle_secs, 60..=1200)
+                                            .text(t("settings.unit_secs_without_bite")),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.startup_delay"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.startup_delay_ms,
+                                            1000..=10000,
+                                        )
+                                        .text("ms"),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.first_cast_delay"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.first_cast_delay_ms,
+                                            0..=30000,
+                                        )
+                                        .text("ms"),
+                                    )
+                                    .on_hover_text(t("settings.first_cast_delay_hint"));
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.auto_start_on_launch,
+                                    t("settings.auto_start"),
+                                );
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.auto_start_countdown"));
+                                    ui.add_enabled(
+                                        self.config.auto_start_on_launch,
+                                        Slider::new(
+                                            &mut self.config.auto_start_delay_secs,
+                                            5..=600,
+                                        )
+                                        .text("s"),
+                                    );
+                                });
+                            });
+
+                        // Discord Webhook
+                        CollapsingHeader::new(t("settings.discord"))
+                            .id_source("settings_discord")
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.webhook_url"));
+                                    ui.add(
+                                        TextEdit::singleline(&mut self.config.webhook_url)
+                                            .desired_width(400.0),
+                                    );
+                                });
+
+                                match self.bot.webhook_outcome() {
+                                    Some(webhook::SendOutcome::Delivered(at)) => {
+                                        ui.colored_label(
+                                            self.emerald(),
+                                            tf(
+                                                "settings.webhook_ok",
+                                                &[("secs", &at.elapsed().as_secs())],
+                                            ),
+                                        );
+                                    }
+                                    Some(webhook::SendOutcome::Failed(error)) => {
+                                        ui.colored_label(
+                                            self.ember_red(),
+                                            tf("settings.webhook_failed", &[("error", &error)]),
+                                        );
+                                    }
+                                    None => {
+                                        ui.label(t("settings.webhook_nothing_sent"));
+                                    }
+                                }
+
+                                ui.checkbox(
+                                    &mut self.config.screenshot_enabled,
+                                    t("settings.screenshots"),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.screenshot_interval"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.screenshot_interval_mins,
+                                            1..=120,
+                                        )
+                                        .text(t("settings.unit_minutes")),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.screenshot_format"));
+                                    ui.radio_value(
+                                        &mut self.config.screenshot_format,
+                                        ScreenshotFormat::Jpeg,
+                                        "JPEG",
+                                    );
+                                    ui.radio_value(
+                                        &mut self.config.screenshot_format,
+                                        ScreenshotFormat::Png,
+                                        "PNG",
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.jpeg_quality"));
+                                    ui.add_enabled(
+                                        self.config.screenshot_format == ScreenshotFormat::Jpeg,
+                                        Slider::new(&mut self.config.jpeg_quality, 10..=100),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.max_screenshot_size"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.screenshot_max_dimension,
+                                            0..=4000,
+                                        )
+                                        .text(t("settings.unit_px_full_size")),
+                                    );
+                                });
+
+                                ui.label(t("settings.notify_on"));
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.checkbox(
+                                        &mut self.config.notify_on_start,
+                                        t("settings.notify_start"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.notify_on_stop,
+                                        t("settings.notify_stop"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.notify_on_pause,
+                                        t("settings.notify_pause"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.notify_on_milestone,
+                                        t("settings.notify_milestones"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.notify_on_error,
+                                        t("settings.notify_errors"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.send_startup_screenshot,
+                                        t("settings.start_screenshot"),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.config.error_screenshot_enabled,
+                                        t("settings.error_screenshots"),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.alert_cooldowns"));
+                                    ui.add(
+                                        DragValue::new(&mut self.config.error_alert_cooldown_secs)
+                                            .clamp_range(0..=3600)
+                                            .prefix(t("settings.cooldown_errors"))
+                                            .suffix(" s"),
+                                    );
+                                    ui.add(
+                                        DragValue::new(&mut self.config.milestone_cooldown_secs)
+                                            .clamp_range(0..=3600)
+                                            .prefix(t("settings.cooldown_milestones"))
+                                            .suffix(" s"),
+                                    );
+                                })
+                                .response
+                                .on_hover_text(t("settings.alert_cooldowns_hint"));
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.messages_per_batch"));
+                                    ui.add(Slider::new(
+                                        &mut self.config.webhook_batch_size,
+                                        1..=20,
+                                    ));
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.message_delay"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.webhook_message_delay_ms,
+                                            0..=5000,
+                                        )
+                                        .text("ms"),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.batch_delay"));
+                                    ui.add(
+                                        Slider::new(
+                                            &mut self.config.webhook_idle_poll_ms,
+                                            100..=10000,
+                                        )
+                                        .text("ms"),
+                                    );
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.webhook_persist_queue,
+                                    t("settings.persist_queue"),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.milestone_every"));
+                                    ui.add(
+                                        Slider::new(&mut self.config.milestone_interval, 0..=500)
+                                            .text(t("settings.unit_fish_off")),
+                                    );
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.milestone_message"));
+                                    ui.add(
+                                        TextEdit::singleline(
+                                            &mut self.config.milestone_message_template,
+                                        )
+                                        .hint_text(t("settings.milestone_message_hint"))
+                                        .desired_width(400.0),
+                                    );
+                                });
+
+                                ui.checkbox(
+                                    &mut self.config.daily_summary_enabled,
+                                    t("settings.daily_summary"),
+                                );
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.summary_time"));
+                                    ui.add(
+                                        DragValue::new(&mut self.config.daily_summary_hour)
+                                            .clamp_range(0..=23),
+                                    );
+                                    ui.label(":");
+                                    ui.add(
+                                        DragValue::new(&mut self.config.daily_summary_minute)
+                                            .clamp_range(0..=59),
+                                    );
+                                    ui.label(t("settings.local_time"));
+                                });
+                            });
+
+                        // OCR Settings
+                        CollapsingHeader::new(t("settings.ocr"))
+                            .id_source("settings_ocr")
+                            .default_open(false)
                             .show(ui, |ui| {
-                                Grid::new("detection_settings")
+                                Grid::new("ocr_settings")
                                     .num_columns(2)
-                                    .spacing([20.0, 8.0])
+                                    .spacing([40.0, 8.0])
                                     .show(ui, |ui| {
-                                        ui.label("Color Tolerance:");
+                                        ui.label(t("settings.ocr_psm"));
                                         ui.add(
-                                            Slider::new(&mut self.config.color_tolerance, 1..=50)
-                                                .text("pixels"),
-                                        );
+                                            DragValue::new(&mut self.config.ocr_psm)
+                                                .clamp_range(0..=13),
+                                        )
+                                        .on_hover_text(t("settings.ocr_psm_hint"));
+                                        ui.end_row();
+
+                                        ui.label("DPI:");
+                                        ui.add(Slider::new(&mut self.config.ocr_dpi, 70..=600));
                                         ui.end_row();
 
-                                        ui.label("Detection Interval:");
+                                        ui.label(t("settings.ocr_whitelist"));
                                         ui.add(
-                                            Slider::new(
-                                                &mut self.config.detection_interval_ms,
-                                                10..=200,
-                                            )
-                                            .text("ms"),
+                                            TextEdit::singleline(&mut self.config.ocr_whitelist)
+                                                .desired_width(160.0),
                                         );
                                         ui.end_row();
 
+                                        ui.label(t("settings.ocr_contrast"));
                                         ui.checkbox(
-                                            &mut self.config.advanced_detection,
-                                            "Advanced Detection (Reduces false positives)",
+                                            &mut self.config.ocr_contrast_stretch,
+                                            t("settings.ocr_contrast_desc"),
                                         );
-                                        ui.label("Uses clustering algorithms for better accuracy");
                                         ui.end_row();
-                                    });
-                            });
 
-                        // Fishing Settings
-                        CollapsingHeader::new("🎣 Fishing Settings")
-                            .default_open(true)
-                            .show(ui, |ui| {
-                                Grid::new("fishing_settings")
-                                    .num_columns(2)
-                                    .spacing([20.0, 8.0])
-                                    .show(ui, |ui| {
-                                        ui.label("Autoclick Interval:");
+                                        ui.label(t("settings.ocr_gamma"));
                                         ui.add(
-                                            Slider::new(
-                                                &mut self.config.autoclick_interval_ms,
-                                                10..=200,
-                                            )
-                                            .text("ms"),
+                                            Slider::new(&mut self.config.ocr_gamma, 0.3..=3.0)
+                                                .text(t("settings.ocr_gamma_desc")),
                                         );
                                         ui.end_row();
 
-                                        ui.label("Fish Per Feed:");
-                                        ui.add(Slider::new(&mut self.config.fish_per_feed, 1..=20));
-                                        ui.end_row();
-
-                                        ui.label("Rod Lure Value:");
-                                        ui.add(
-                                            Slider::new(&mut self.config.rod_lure_value, 0.1..=5.0)
-                                                .step_by(0.1),
+                                        ui.label(t("settings.ocr_invert"));
+                                        ui.checkbox(
+                                            &mut self.config.ocr_invert,
+                                            t("settings.ocr_invert_desc"),
                                         );
                                         ui.end_row();
 
-                                        ui.label("Bite Timeout:");
-                                        ui.label(self.config.get_timeout_description());
+                                        ui.label(t("settings.tesseract_path"));
+                                        ui.add(
+                                            TextEdit::singleline(&mut self.config.tesseract_path)
+                                                .hint_text(t("settings.tesseract_path_placeholder"))
+                                                .desired_width(260.0),
+                                        )
+                                        .on_hover_text(t("settings.tesseract_path_hint"));
                                         ui.end_row();
 
-                                        ui.label("Max Fishing Timeout:");
-                                        ui.add(
-                                            Slider::new(
-                                                &mut self.config.max_fishing_timeout_ms,
-                                                5000..=60000,
-                                            )
-                                            .text("ms"),
-                                        );
+                                        ui.label("Tesseract:");
+                                        match ocr::tesseract_error() {
+                                            Some(error) => {
+                                                ui.colored_label(self.ember_red(), error);
+                                            }
+                                            None => {
+                                                ui.colored_label(
+                                                    self.emerald(),
+                                                    t("settings.tesseract_found"),
+                                                );
+                                            }
+                                        }
                                         ui.end_row();
                                     });
+
+                                ui.horizontal(|ui| {
+                                    if ui.button(t("settings.test_hunger_ocr")).clicked() {
+                                        match self.bot.test_hunger_ocr(&self.config) {
+                                            Ok((hunger, dir)) => {
+                                                let value = hunger
+                                                    .map(|h| format!("{}%", h))
+                                                    .unwrap_or_else(|| "-".to_string());
+                                                self.update_status(tf(
+                                                    "status.ocr_test_result",
+                                                    &[("value", &value), ("path", &dir.display())],
+                                                ));
+                                            }
+                                            Err(e) => self.update_status(tf(
+                                                "status.ocr_test_failed",
+                                                &[("error", &e)],
+                                            )),
+                                        }
+                                    }
+
+                                    if ui.button(t("settings.reset_ocr")).clicked() {
+                                        self.config.reset_ocr_settings();
+                                    }
+                                });
                             });
 
-                        // Safety Settings
-                        CollapsingHeader::new("🛡️ Safety Settings")
-                            .default_open(true)
+                        // Remote Monitoring
+                        CollapsingHeader::new(t("settings.remote"))
+                            .id_source("settings_remote")
+                            .default_open(false)
                             .show(ui, |ui| {
                                 ui.checkbox(
-                                    &mut self.config.failsafe_enabled,
-                                    "Enable Failsafe (Stop on mouse corner)",
-                                );
-                                ui.checkbox(
-                                    &mut self.config.auto_save_enabled,
-                                    "Auto-save Configuration",
+                                    &mut self.config.status_server_enabled,
+                                    t("settings.http_server"),
                                 );
 
                                 ui.horizontal(|ui| {
-                                    ui.label("Startup Delay:");
+                                    ui.label(t("settings.port"));
                                     ui.add(
-                                        Slider::new(
-                                            &mut self.config.startup_delay_ms,
-                                            1000..=10000,
-                                        )
-                                        .text("ms"),
+                                        DragValue::new(&mut self.config.status_server_port)
+                                            .clamp_range(1024..=65535),
                                     );
                                 });
-                            });
 
-                        // Discord Webhook
-                        CollapsingHeader::new("📢 Discord Integration")
-                            .default_open(false)
-                            .show(ui, |ui| {
                                 ui.horizontal(|ui| {
-                                    ui.label("Webhook URL:");
+                                    ui.label(t("settings.websocket_port"));
                                     ui.add(
-                                        TextEdit::singleline(&mut self.config.webhook_url)
-                                            .desired_width(400.0),
+                                        DragValue::new(&mut self.config.status_ws_port)
+                                            .clamp_range(1024..=65535),
+                                    );
+                                    ui.label(t("settings.websocket_hint"));
+                                });
+
+                                ui.horizontal(|ui| {
+                                    ui.label(t("settings.control_token"));
+                                    ui.add(
+                                        TextEdit::singleline(&mut self.config.status_server_token)
+                                            .password(true)
+                                            .hint_text(t("settings.control_token_hint"))
+                                            .desired_width(260.0),
                                     );
                                 });
 
+                                ui.label(t("settings.remote_restart_hint"));
+                            });
+
+                        // Sounds
+                        CollapsingHeader::new(t("settings.sounds"))
+                            .id_source("settings_sounds")
+                            .default_open(false)
+                            .show(ui, |ui| {
                                 ui.checkbox(
-                                    &mut self.config.screenshot_enabled,
-                                    "Enable Screenshots",
+                                    &mut self.config.sound_on_catch,
+                                    t("settings.sound_on_catch"),
+                                );
+                                ui.checkbox(
+                                    &mut self.config.sound_on_error_stop,
+                                    t("settings.sound_on_error"),
                                 );
 
                                 ui.horizontal(|ui| {
-                                    ui.label("Screenshot Interval:");
+                                    ui.label(t("settings.volume"));
                                     ui.add(
-                                        Slider::new(
-                                            &mut self.config.screenshot_interval_mins,
-                                            1..=120,
-                                        )
-                                        .text("minutes"),
+                                        Slider::new(&mut self.config.sound_volume, 0.0..=1.0)
+                                            .show_value(false),
                                     );
+                                    if ui.button(t("settings.play_chime")).clicked() {
+                                        sound::play(sound::Cue::Catch, self.config.sound_volume);
+                                    }
+                                    if ui.button(t("settings.play_alert")).clicked() {
+                                        sound::play(sound::Cue::Alert, self.config.sound_volume);
+                                    }
                                 });
                             });
 
                         // Resolution Presets
-                        CollapsingHeader::new("🖥️ Resolution Presets")
+                        CollapsingHeader::new(t("settings.resolution"))
+                            .id_source("settings_resolution")
                             .default_open(false)
                             .show(ui, |ui| {
                                 ui.horizontal(|ui| {
-                                    ui.label("Preset:");
+                                    ui.label(t("settings.preset"));
                                     ComboBox::from_label("")
                                         .selected_text(&self.config.region_preset)
                                         .show_ui(ui, |ui| {
@@ -2608,71 +9868,400 @@ mod ui {
                                                     name,
                                                 );
                                             }
+                                            let custom: Vec<String> = self
+                                                .config
+                                                .custom_presets
+                                                .iter()
+                                                .map(|preset| preset.name.clone())
+                                                .collect();
+                                            for name in custom {
+                                                ui.selectable_value(
+                                                    &mut self.config.region_preset,
+                                                    name.clone(),
+                                                    &name,
+                                                );
+                                            }
                                         });
 
-                                    if ui.button("Apply").clicked() {
+                                    if ui.button(t("settings.apply")).clicked() {
                                         let selected_preset = self.config.region_preset.clone();
                                         self.config.apply_resolution_preset(&selected_preset);
                                     }
+
+                                    let selected_preset = self.config.region_preset.clone();
+                                    let is_custom = self
+                                        .config
+                                        .custom_presets
+                                        .iter()
+                                        .any(|preset| preset.name == selected_preset);
+                                    if is_custom && ui.button(t("settings.delete_preset")).clicked()
+                                    {
+                                        self.config.delete_region_preset(&selected_preset);
+                                        self.update_status(tf(
+                                            "status.preset_deleted",
+                                            &[("name", &selected_preset)],
+                                        ));
+                                    }
                                 });
 
-                                ui.label(format!(
-                                    "Red Region: ({}, {}) {}x{}",
-                                    self.config.red_region.x,
-                                    self.config.red_region.y,
-                                    self.config.red_region.width,
-                                    self.config.red_region.height
-                                ));
-                                ui.label(format!(
-                                    "Yellow Region: ({}, {}) {}x{}",
-                                    self.config.yellow_region.x,
-                                    self.config.yellow_region.y,
-                                    self.config.yellow_region.width,
-                                    self.config.yellow_region.height
-                                ));
-                                ui.label(format!(
-                                    "Hunger Region: ({}, {}) {}x{}",
-                                    self.config.hunger_region.x,
-                                    self.config.hunger_region.y,
-                                    self.config.hunger_region.width,
-                                    self.config.hunger_region.height
-                                ));
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        TextEdit::singleline(&mut self.new_preset_name)
+                                            .hint_text(t("settings.preset_name_hint"))
+                                            .desired_width(160.0),
+                                    );
+                                    let name = self.new_preset_name.trim().to_string();
+                                    // Built-in names would be shadowed by the built-in regions
+                                    let valid = !name.is_empty()
+                                        && !self.resolution_presets.contains_key(&name);
+                                    if ui
+                                        .add_enabled(valid, Button::new(t("settings.save_preset")))
+                                        .on_hover_text(t("settings.save_preset_hint"))
+                                        .clicked()
+                                    {
+                                        self.config.save_region_preset(&name);
+                                        self.new_preset_name.clear();
+                                        self.update_status(tf(
+                                            "status.preset_saved",
+                                            &[("name", &name)],
+                                        ));
+                                    }
+                                });
+
+                                Grid::new("region_grid")
+                                    .num_columns(2)
+                                    .spacing([10.0, 6.0])
+                                    .show(ui, |ui| {
+                                        ui.label(t("settings.red_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("red_region"),
+                                            &mut self.config.red_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.yellow_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("yellow_region"),
+                                            &mut self.config.yellow_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+
+                                        ui.label(t("settings.hunger_region"));
+                                        Self::region_editor(
+                                            ui,
+                                            Id::new("hunger_region"),
+                                            &mut self.config.hunger_region,
+                                            &mut self.region_nudge,
+                                        );
+                                        ui.end_row();
+                                    });
                             });
 
                         ui.add_space(20.0);
 
                         // Action Buttons
                         ui.horizontal(|ui| {
-                            if ui.button("💾 Save Settings").clicked() {
+                            if ui.button(t("settings.save")).clicked() {
                                 if let Err(e) = self.config.save() {
-                                    self.update_status(format!(
-                                        "❌ Failed to save settings: {}",
-                                        e
+                                    self.update_status(tf(
+                                        "status.settings_save_failed",
+                                        &[("error", &e)],
                                     ));
                                 } else {
-                                    self.update_status(
-                                        "✅ Settings saved successfully!".to_string(),
-                                    );
+                                    self.update_status(t("status.settings_saved").to_string());
                                     self.show_settings = false;
                                 }
                             }
 
-                            if ui.button("🔄 Reset to Defaults").clicked() {
-                                self.config = BotConfig::default();
-                                self.update_status("🔄 Settings reset to defaults".to_string());
+                            if ui.button(t("settings.reset")).clicked() {
+                                self.reset_clears_webhook = false;
+                                self.confirm_reset = true;
                             }
 
-                            if ui.button("❌ Cancel").clicked() {
+                            if ui.button(t("settings.cancel")).clicked() {
                                 self.config = BotConfig::load().unwrap_or_default();
                                 self.show_settings = false;
                             }
+
+                            ui.menu_button(t("settings.restore_previous"), |ui| {
+                                let snapshots = BotConfig::snapshots();
+                                if snapshots.is_empty() {
+                                    ui.label(t("settings.no_snapshots"));
+                                }
+                                for (path, saved_at) in snapshots {
+                                    let when = saved_at.format("%Y-%m-%d %H:%M").to_string();
+                                    if ui.button(&when).clicked() {
+                                        match BotConfig::load_snapshot(&path) {
+                                            Ok(config) => {
+                                                self.config = config;
+                                                self.update_status(tf(
+                                                    "status.snapshot_loaded",
+                                                    &[("time", &when)],
+                                                ));
+                                            }
+                                            Err(e) => self.update_status(tf(
+                                                "status.snapshot_load_failed",
+                                                &[("error", &e)],
+                                            )),
+                                        }
+                                        ui.close_menu();
+                                    }
+                                }
+                            });
+
+                            if ui
+                                .button(t("settings.self_check"))
+                                .on_hover_text(t("settings.self_check_hint"))
+                                .clicked()
+                            {
+                                self.run_self_check();
+                            }
+
+                            if ui
+                                .button(t("settings.copy_diagnostics"))
+                                .on_hover_text(t("settings.copy_diagnostics_hint"))
+                                .clicked()
+                            {
+                                ui.ctx().copy_text(self.diagnostics_report());
+                                self.update_status(t("status.diagnostics_copied").to_string());
+                            }
                         });
                     });
                 });
         }
 
+        /// Plain-text summary for bug reports. The webhook URL and status token
+        /// are masked.
+        fn diagnostics_report(&self) -> String {
+            use std::fmt::Write;
+
+            let mut report = String::new();
+            writeln!(report, "Arcane Fishing Bot {}", env!("CARGO_PKG_VERSION")).ok();
+            writeln!(
+                report,
+                "OS: {} ({})",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            )
+            .ok();
+
+            match screenshots::Screen::all() {
+                Ok(screens) => {
+                    for (index, screen) in screens.iter().enumerate() {
+                        let info = &screen.display_info;
+                        writeln!(
+                            report,
+                            "Screen {}: {}x{} at ({}, {}), scale {}",
+                            index, info.width, info.height, info.x, info.y, info.scale_factor
+                        )
+                        .ok();
+                    }
+                }
+                Err(e) => {
+                    writeln!(report, "Screens: unavailable ({})", e).ok();
+                }
+            }
+
+            let state = self.bot.get_state();
+            writeln!(
+                report,
+                "Bot: running={} paused={} phase={:?} fish={} errors={}",
+                state.running,
+                state.paused,
+                state.current_phase,
+                state.fish_count,
+                state.errors_count
+            )
+            .ok();
+
+            writeln!(report, "\nRecent errors:").ok();
+            if state.recent_errors.is_empty() {
+                writeln!(report, "  none").ok();
+            }
+            for error in &state.recent_errors {
+                writeln!(report, "  {}", error).ok();
+            }
+
+            let mut config = serde_json::to_value(&self.config).unwrap_or_default();
+            for key in ["webhook_url", "status_server_token"] {
+                if let Some(value) = config.get_mut(key) {
+                    if value.as_str().is_some_and(|s| !s.is_empty()) {
+                        *value = serde_json::Value::from("<redacted>");
+                    }
+                }
+            }
+            writeln!(
+                report,
+                "\nConfig:\n{}",
+                serde_json::to_string_pretty(&config).unwrap_or_default()
+            )
+            .ok();
+
+            report
+        }
+
+        /// Live capture of the region selected for nudging, refreshed as it moves.
+        fn render_region_preview(&mut self, ctx: &Context) {
+            let Some(region) = self.region_nudge.region else {
+                self.region_nudge.preview = None;
+                return;
+            };
+
+            let stale = match &self.region_nudge.preview {
+                Some((shown, captured_at, _)) => {
+                    *shown != region || captured_at.elapsed() >= REGION_PREVIEW_REFRESH
+                }
+                None => true,
+            };
+            if stale {
+                if let Ok(image) = self.bot.capture_region(region) {
+                    let size = [image.width() as usize, image.height() as usize];
+                    let pixels = ColorImage::from_rgba_unmultiplied(size, image.as_raw());
+                    let texture =
+                        ctx.load_texture("region_preview", pixels, TextureOptions::NEAREST);
+                    self.region_nudge.preview = Some((region, Instant::now(), texture));
+                }
+            }
+            ctx.request_repaint_after(REGION_PREVIEW_REFRESH);
+
+            Window::new(t("settings.region_preview"))
+                .id(Id::new("region_preview_window"))
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(format!(
+                        "({}, {}) {}x{}",
+                        region.x, region.y, region.width, region.height
+                    ));
+                    if let Some((_, _, texture)) = &self.region_nudge.preview {
+                        let size = texture.size_vec2();
+                        let scale = (240.0 / size.x.max(size.y)).min(4.0);
+                        ui.image((texture.id(), size * scale));
+                    }
+                });
+        }
+
+        fn render_resume_offer(&mut self, ctx: &Context) {
+            // Starting some other way replaces the crashed session
+            if self.bot.get_state().running {
+                self.resume_offer = None;
+                return;
+            }
+            let Some(checkpoint) = self.resume_offer.clone() else {
+                return;
+            };
+
+            let saved_at = chrono::DateTime::parse_from_rfc3339(&checkpoint.saved_at)
+                .map(|at| at.with_timezone(&chrono::Local).format("%H:%M").to_string())
+                .unwrap_or_default();
+            let runtime = format!(
+                "{}h {}m",
+                checkpoint.runtime_seconds / 3600,
+                (checkpoint.runtime_seconds % 3600) / 60
+            );
+
+            Window::new(t("controls.resume_title"))
+                .id(Id::new("resume_session_window"))
+                .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(tf(
+                        "controls.resume_body",
+                        &[
+                            ("time", &saved_at),
+                            ("fish", &checkpoint.fish),
+                            ("runtime", &runtime),
+                        ],
+                    ));
+                    ui.add_space(8.0);
+
+                    ui.horizontal(|ui| {
+                        if ui.button(t("controls.resume_session")).clicked() {
+                            self.resume_offer = None;
+                            self.auto_start_at = None;
+                            self.bot.resume_session(&checkpoint);
+                            self.update_status(t("status.session_resumed").to_string());
+                        }
+
+                        if ui.button(t("controls.discard_session")).clicked() {
+                            self.resume_offer = None;
+                            config::SessionCheckpoint::clear();
+                        }
+                    });
+                });
+        }
+
+        fn render_accessibility_prompt(&mut self, ctx: &Context) {
+            Window::new(t("controls.accessibility_title"))
+                .id(Id::new("accessibility_window"))
+                .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(t("controls.accessibility_body"));
+                    ui.add_space(8.0);
+
+                    ui.horizontal(|ui| {
+                        if ui.button(t("controls.accessibility_open")).clicked() {
+                            if let Err(e) = input::open_accessibility_settings() {
+                                self.update_status(tf(
+                                    "status.accessibility_open_failed",
+                                    &[("error", &e)],
+                                ));
+                            }
+                        }
+
+                        if ui.button(t("controls.accessibility_recheck")).clicked() {
+                            self.accessibility_prompt = !input::accessibility_trusted();
+                        }
+
+                        if ui.button(t("controls.accessibility_dismiss")).clicked() {
+                            self.accessibility_prompt = false;
+                        }
+                    });
+                });
+        }
+
+        fn render_reset_confirm(&mut self, ctx: &Context) {
+            Window::new(t("settings.reset_confirm_title"))
+                .id(Id::new("reset_confirm_window"))
+                .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(t("settings.reset_confirm_body"));
+                    ui.checkbox(
+                        &mut self.reset_clears_webhook,
+                        t("settings.reset_clear_webhook"),
+                    );
+                    ui.add_space(8.0);
+
+                    ui.horizontal(|ui| {
+                        if ui.button(t("settings.reset_confirm")).clicked() {
+                            let webhook_url = std::mem::take(&mut self.config.webhook_url);
+                            self.config = BotConfig::default();
+                            if !self.reset_clears_webhook {
+                                self.config.webhook_url = webhook_url;
+                            }
+                            self.confirm_reset = false;
+                            self.update_status(t("status.settings_reset").to_string());
+                        }
+
+                        if ui.button(t("settings.cancel")).clicked() {
+                            self.confirm_reset = false;
+                        }
+                    });
+                });
+        }
+
         fn render_advanced_stats_window(&mut self, ctx: &Context) {
-            Window::new("📊 Advanced Statistics")
+            Window::new(t("stats.title"))
+                .id(Id::new("advanced_stats_window"))
                 .default_size([600.0, 500.0])
                 .collapsible(false)
                 .show(ctx, |ui| {
@@ -2682,57 +10271,186 @@ mod ui {
                     let last_action = self
                         .bot
                         .get_last_action_elapsed()
-                        .map(|elapsed| format!("{:.1}s ago", elapsed.as_secs_f32()))
-                        .unwrap_or_else(|| "Unavailable".to_string());
+                        .map(|elapsed| {
+                            tf(
+                                "stats.seconds_ago",
+                                &[("seconds", &format!("{:.1}", elapsed.as_secs_f32()))],
+                            )
+                        })
+                        .unwrap_or_else(|| t("stats.unavailable").to_string());
 
-                    ui.heading("📈 Detailed Analytics");
+                    ui.heading(t("stats.heading"));
                     ui.separator();
 
                     Grid::new("advanced_stats")
                         .num_columns(2)
                         .spacing([40.0, 12.0])
                         .show(ui, |ui| {
-                            ui.label(RichText::new("Average Fish/Hour:").strong());
+                            ui.label(RichText::new(t("stats.avg_fish_per_hour")).strong());
                             ui.label(format!("{:.2}", lifetime.average_fish_per_hour));
                             ui.end_row();
 
-                            ui.label(RichText::new("Total Feeds:").strong());
+                            ui.label(RichText::new(t("stats.total_feeds")).strong());
                             ui.label(format!("{}", lifetime.total_feeds));
                             ui.end_row();
 
-                            ui.label(RichText::new("Current Session Best:").strong());
+                            ui.label(RichText::new(t("stats.session_best")).strong());
                             ui.label(format!("{}", state.session_best_streak));
                             ui.end_row();
 
-                            ui.label(RichText::new("All-time Best Session:").strong());
-                            ui.label(format!("{} fish", lifetime.best_session_fish));
+                            ui.label(RichText::new(t("stats.all_time_best")).strong());
+                            ui.label(tf(
+                                "stats.fish_suffix",
+                                &[("count", &lifetime.best_session_fish)],
+                            ));
                             ui.end_row();
 
-                            ui.label(RichText::new("System Uptime:").strong());
-                            ui.label(format!("{:.1}%", state.uptime_percentage));
+                            ui.label(RichText::new(t("stats.system_uptime")).strong());
+                            ui.label(Self::percent_or_dash(state.uptime_percentage));
                             ui.end_row();
 
-                            ui.label(RichText::new("Last Input Action:").strong());
+                            ui.label(RichText::new(t("stats.last_input")).strong());
                             ui.label(last_action);
                             ui.end_row();
 
-                            ui.label(RichText::new("Lifetime Runtime:").strong());
+                            ui.label(RichText::new(t("stats.lifetime_runtime")).strong());
                             ui.label(runtime);
                             ui.end_row();
 
-                            ui.label(RichText::new("Error Count:").strong());
+                            ui.label(RichText::new(t("stats.error_count")).strong());
                             ui.label(format!("{}", state.errors_count));
                             ui.end_row();
+
+                            ui.label(RichText::new(t("stats.auto_recoveries")).strong());
+                            ui.label(format!("{}", state.auto_recoveries));
+                            ui.end_row();
+
+                            let catch_times = self.bot.catch_times();
+                            ui.label(RichText::new(t("stats.catch_times")).strong());
+                            ui.label(match catch_times.last() {
+                                Some(last) => tf(
+                                    "stats.catch_times_value",
+                                    &[
+                                        ("count", &catch_times.len()),
+                                        ("time", &last.format("%H:%M:%S")),
+                                    ],
+                                ),
+                                None => t("stats.unavailable").to_string(),
+                            });
+                            ui.end_row();
+
+                            ui.label(RichText::new(t("stats.days_active")).strong());
+                            ui.label(format!("{}", lifetime.days_active));
+                            ui.end_row();
+
+                            ui.label(RichText::new(t("stats.fish_per_active_day")).strong());
+                            ui.label(format!("{:.1}", lifetime.fish_per_active_day()));
+                            ui.end_row();
+
+                            ui.label(RichText::new(t("stats.day_streak")).strong());
+                            ui.label(tf(
+                                "stats.day_streak_value",
+                                &[
+                                    ("current", &lifetime.current_day_streak),
+                                    ("best", &lifetime.best_day_streak),
+                                ],
+                            ));
+                            ui.end_row();
+                        });
+
+                    ui.add_space(20.0);
+
+                    ui.label(RichText::new(t("stats.phase_breakdown")).strong());
+                    let breakdown = state.phase_breakdown(Instant::now());
+                    let total: Duration = breakdown.iter().map(|(_, time)| *time).sum();
+                    if total.is_zero() {
+                        ui.label(t("stats.unavailable"));
+                    } else {
+                        Grid::new("phase_breakdown")
+                            .num_columns(3)
+                            .spacing([40.0, 6.0])
+                            .show(ui, |ui| {
+                                for (phase, time) in &breakdown {
+                                    let secs = time.as_secs();
+                                    ui.label(Self::phase_label(phase));
+                                    ui.label(format!(
+                                        "{:02}:{:02}:{:02}",
+                                        secs / 3600,
+                                        (secs % 3600) / 60,
+                                        secs % 60
+                                    ));
+                                    ui.label(format!(
+                                        "{:.1}%",
+                                        time.as_secs_f32() / total.as_secs_f32() * 100.0
+                                    ));
+                                    ui.end_row();
+                                }
+                            });
+                    }
+
+                    ui.add_space(20.0);
+
+                    ui.label(RichText::new(t("stats.fish_per_day")).strong());
+                    let days = lifetime.fish_per_day(14);
+                    let bars = days
+                        .iter()
+                        .enumerate()
+                        .map(|(i, (date, fish))| {
+                            Bar::new(i as f64, *fish as f64)
+                                .width(0.7)
+                                .name(date.format("%b %d"))
+                        })
+                        .collect();
+                    let labels: Vec<String> = days
+                        .iter()
+                        .map(|(date, _)| date.format("%m/%d").to_string())
+                        .collect();
+                    Plot::new("fish_per_day")
+                        .height(160.0)
+                        .allow_zoom(false)
+                        .allow_drag(false)
+                        .allow_scroll(false)
+                        .include_y(0.0)
+                        .x_axis_formatter(move |x, _, _| {
+                            if x >= 0.0 && x.fract() == 0.0 {
+                                labels.get(x as usize).cloned().unwrap_or_default()
+                            } else {
+                                String::new()
+                            }
+                        })
+                        .show(ui, |plot_ui| {
+                            plot_ui.bar_chart(BarChart::new(bars).color(self.arcane_blue()));
                         });
 
                     ui.add_space(20.0);
 
-                    if ui.button("🗑️ Reset All Statistics").clicked() {
+                    if ui.button(t("stats.export_csv")).clicked() {
+                        let file_name =
+                            format!("fishing_stats_{}.csv", Local::now().format("%Y%m%d_%H%M%S"));
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter("CSV", &["csv"])
+                            .set_file_name(file_name.as_str())
+                            .save_file()
+                        {
+                            match lifetime.export_csv(&path) {
+                                Ok(()) => self.update_status(tf(
+                                    "status.stats_exported",
+                                    &[("path", &path.display())],
+                                )),
+                                Err(e) => self.update_status(tf(
+                                    "status.stats_export_failed",
+                                    &[("error", &e)],
+                                )),
+                            }
+                        }
+                    }
+
+                    if ui.button(t("stats.reset_all")).clicked() {
                         // Note: This would require implementing a reset method
-                        self.update_status("⚠️ Statistics reset not implemented yet".to_string());
+                        self.update_status(t("status.stats_reset_unavailable").to_string());
                     }
 
-                    if ui.button("❌ Close").clicked() {
+                    if ui.button(t("stats.close")).clicked() {
                         self.show_advanced_stats = false;
                     }
                 });